@@ -38,71 +38,71 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("m1 * m2 = {}", m1.mul_by_ref(&m2)?);
     println!("m1 * m2 = {}", m1.clone() * m2.clone());
     // m1 * m2 = Matrix (2x2) = [
-    //  (6+38i) (-32-26i)
-    //  (-32-26i) (-13-10i)
+    //    (6+38i) (-32-26i)
+    //  (-13-10i)   (17-4i)
     // ]
     // m1 * m2 = Matrix (2x2) = [
-    //  (6+38i) (-32-26i)
-    //  (-32-26i) (-13-10i)
+    //    (6+38i) (-32-26i)
+    //  (-13-10i)   (17-4i)
     // ]
 
     println!("m * 2 = {}", m1.mul_num(2.r()));
     // m * 2 = Matrix (2x2) = [
     //  (-6i-2) (4i+8)
-    //  (4i+8) (2i+2)
+    //   (2i+2) (2i-4)
     // ]
 
     println!("m2^3 = {}", m2.pow(3)?);
     // m2^3 = Matrix (2x2) = [
-    //  (-608i-70) (422+476i)
-    //  (422+476i) (369+608i)
+    //  (-608i-70)  (422+476i)
+    //  (369+608i) (-239i-678)
     // ]
 
     println!("m1 + m2 = {}", m1.add_by_ref(&m2)?);
     println!("m1 + m2 = {}", m1.clone() + m2.clone());
     // m1 + m2 = Matrix (2x2) = [
-    //  (-6i-6) (4i+8)
-    //  (4i+8) (4i+5)
+    //  (-6i-6)  (4i+8)
+    //   (4i+5) (2i-10)
     // ]
     // m1 + m2 = Matrix (2x2) = [
-    //  (-6i-6) (4i+8)
-    //  (4i+8) (4i+5)
+    //  (-6i-6)  (4i+8)
+    //   (4i+5) (2i-10)
     // ]
 
     println!("m1 - m2 = {}", m1.sub_by_ref(&m2)?);
     println!("m1 - m2 = {}", m1.clone() - m2.clone());
     // m1 - m2 = Matrix (2x2) = [
-    // 4 0
-    // 0 (-2i-3)
+    //        4 0
+    //  (-2i-3) 6
     // ]
     // m1 - m2 = Matrix (2x2) = [
-    // 4 0
-    // 0 (-2i-3)
+    //        4 0
+    //  (-2i-3) 6
     // ]
 
     println!("Cofactor matrix of m = {}", m1.cofactor_matrix().unwrap());
     // Cofactor matrix of m = Matrix (2x2) = [
-    //  (i-2) (i+1)
-    //  (i+1) (-2i-4)
+    //    (i-2)  (-i-1)
+    //  (-2i-4) (-3i-1)
     // ]
 
     println!("Transposed m = {}", m1.transpose());
     // Transposed m = Matrix (2x2) = [
     //  (-1-3i) (1+i)
-    //  (1+i) (4+2i)
+    //   (4+2i) (i-2)
     // ]
 
     println!("im = {}", m1.inverse().unwrap());
     // im = Matrix (2x2) = [
-    //  (i-2)/(3-i) (-2i-4)/(3-i)
-    //  (-2i-4)/(3-i) (i+1)/(3-i)
+    //   (0.1i-0.7) (-1-i)
+    //  (-0.2-0.4i)     -i
     // ]
 
     for (i, x) in m1.slae(d)?.unwrap().iter().enumerate() {
-        println!("x{} = {}", i+1, x);
+        println!("x{} = {:.4}", i+1, x);
     }
-    // x1 = (-7-5i)/(3-i)
-    // x2 = (-1-7i)/(3-i)
+    // x1 = (-1.6-2.2i)
+    // x2 = (0.4-2.2i)
 
 
     Ok(())
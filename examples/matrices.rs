@@ -11,7 +11,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         0.0, 1.0, 7.0, 8.0, 6.0,
         0.0, 1.0, 4.0, 4.0, 7.0,
     ])?;
-    println!("det = {}", m.det());
+    println!("det = {:.1}", m.det());
     // det = 156.0
 
 
@@ -29,14 +29,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("m1 * m2 = {}", m1.mul_by_ref(&m2)?);
     println!("m1 * m2 = {}", m1 * m2);
     // m1 * m2 = Matrix (3x3) = [
-    // 18 12 12
-    // 12 12 39
-    // 12 39 30
+    //  18 12 12
+    //  39 30 33
+    //  18 12 12
     // ]
     // m1 * m2 = Matrix (3x3) = [
-    // 18 12 12
-    // 12 12 39
-    // 12 39 30
+    //  18 12 12
+    //  39 30 33
+    //  18 12 12
     // ]
 
 
@@ -48,9 +48,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     ])?;
     println!("m * 2 = {}", m.mul_num(2.0));
     // m * 2 = Matrix (3x3) = [
-    // 2 4 6
-    // 4 6 2
-    // 6 2 4
+    //   2 4 6
+    //   2 4 6
+    //  10 4 2
     // ]
 
 
@@ -68,14 +68,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("m1 + m2 = {}", m1.add_by_ref(&m2)?);
     println!("m1 + m2 = {}", m1 + m2);
     // m1 + m2 = Matrix (3x3) = [
-    // 2 3 5
-    // 3 5 7
-    // 5 7 1
+    //   2  3  5
+    //   7  1 11
+    //  13 15 17
     // ]
     // m1 + m2 = Matrix (3x3) = [
-    // 2 3 5
-    // 3 5 7
-    // 5 7 1
+    //   2  3  5
+    //   7  1 11
+    //  13 15 17
     // ]
 
 
@@ -93,14 +93,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("m1 - m2 = {}", m1.sub_by_ref(&m2)?);
     println!("m1 - m2 = {}", m1 - m2);
     // m1 - m2 = Matrix (3x3) = [
-    // 0 1 1
-    // 1 1 1
-    // 1 1 9
+    //  0 1 1
+    //  1 9 1
+    //  1 1 1
     // ]
     // m1 - m2 = Matrix (3x3) = [
-    // 0 1 1
-    // 1 1 1
-    // 1 1 9
+    //  0 1 1
+    //  1 9 1
+    //  1 1 1
     // ]
 
 
@@ -110,11 +110,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         3.0, -4.0, 5.0,
         6.0, 7.0, 8.0,
     ])?;
-    println!("Cofactor matrix of m = {}", m.cofactor_matrix().unwrap());
+    println!("Cofactor matrix of m = {:.1}", m.cofactor_matrix().unwrap());
     // Cofactor matrix of m = Matrix (3x3) = [
-    // -67 6 45
-    // 6 45 6
-    // 45 6 -4
+    //  -67.0  6.0 45.0
+    //    6.0 -4.0 -1.0
+    //   13.0  1.0 -7.0
     // ]
 
 
@@ -126,9 +126,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     ])?;
     println!("Transposed m = {}", m.transpose());
     // Transposed m = Matrix (3x3) = [
-    // 1 3 6
-    // 3 6 1
-    // 6 1 -4
+    //  1  3 6
+    //  1 -4 7
+    //  2  5 8
     // ]
 
 
@@ -139,11 +139,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         6.0, 7.0, 8.0,
     ])?;
     let im = m.inverse().unwrap();
-    println!("im = {}", im);
+    println!("im = {:.4}", im);
     // im = Matrix (3x3) = [
-    // -2.310344827586207 0.20689655172413793 0.4482758620689655
-    // 0.20689655172413793 0.4482758620689655 0.20689655172413793
-    // 0.4482758620689655 0.20689655172413793 -0.13793103448275862
+    //  -2.3103  0.2069  0.4483
+    //   0.2069 -0.1379  0.0345
+    //   1.5517 -0.0345 -0.2414
     // ]
 
 
@@ -154,10 +154,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         6.0, 7.0, 8.0,
     ])?;
     println!("m^3 = {}", m.pow(3)?);
-    // m = Matrix (3x3) = [
-    // 187 133 271
-    // 133 271 339
-    // 271 339 -13
+    // m^3 = Matrix (3x3) = [
+    //  187 133  271
+    //  339 -13  520
+    //  843 716 1208
     // ]
 
 
@@ -192,11 +192,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         2.0, 1.0,
     ])?;
     println!("(m1 * x) + (m2 * 2) - m3 = 0");
-    println!("x = {}", (m3 - m2.mul_num(2.0)) * m1.inverse().unwrap() );
+    println!("x = {:.1}", (m3 - m2.mul_num(2.0)) * m1.inverse().unwrap() );
     // (m1 * x) + (m2 * 2) - m3 = 0
     // x = Matrix (2x2) = [
-    // 11 -7
-    // -7 1
+    //  11.0 -7.0
+    //   1.0 -2.0
     // ]
 
 
@@ -218,8 +218,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("x = {}", m1.inverse().unwrap() * m3 * m2.inverse().unwrap() );
     // m1 * x * m2 = m3
     // x = Matrix (2x2) = [
-    // -0.1875 -0.1875
-    // -0.1875 0.0625
+    //  -0.1875 -0.1875
+    //   0.0625  2.0625
     // ]
 
 
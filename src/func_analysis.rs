@@ -4,6 +4,7 @@ use crate::utils::AdvancedEQ;
 
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     x: f64,
     y: f64,
@@ -8,12 +8,21 @@ pub(crate) enum Sign {
     None,
 }
 
+/// Imaginary (complex) number, real * i^im_pow, or the mixed expression (mixed_base)^(mixed_pow) * mixed_mul,
+/// where the mixed parts are sums of such numbers.
+/// With the `serde` feature it is (de)serialized with the same structure, the absent mixed parts are omitted:
+/// 2i is {"real": 2.0, "im_pow": 1.0}, (1-i)^i is {"real": 0.0, "im_pow": 0.0,
+/// "mixed_base": [{"real": 1.0, "im_pow": 0.0}, {"real": -1.0, "im_pow": 1.0}], "mixed_pow": [{"real": 1.0, "im_pow": 1.0}]}.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Im {
     pub(crate) real: f64,
     pub(crate) im_pow: f64,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub(crate) mixed_base: Option<Vec<Self>>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub(crate) mixed_pow: Option<Vec<Self>>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub(crate) mixed_mul: Option<Vec<Self>>,
 }
 
@@ -4,7 +4,10 @@ use crate::im::core::Im;
 
 impl Display for Im {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.format())
+        match f.precision() {
+            Some(p) => write!(f, "{}", self.round(p).format()),
+            None => write!(f, "{}", self.format()),
+        }
     }
 }
 
@@ -114,6 +117,47 @@ impl Im {
         "".to_string()
     }
 
+    pub(crate) fn format_python(&self) -> String {
+        // 2i -> 2j, i -> 1j, ^ -> **, 2(1+i) -> 2*(1+1j)
+        let mut out = String::new();
+        let mut prev: Option<char> = None;
+        for c in self.format().chars() {
+            let number = prev.is_some_and(|p| p.is_ascii_digit() || p == '.');
+            match c {
+                'i' if number => out.push('j'),
+                'i' => out.push_str("1j"),
+                '^' => out.push_str("**"),
+                '(' if number || prev.is_some_and(|p| p == 'i' || p == ')') => out.push_str("*("),
+                _ => out.push(c),
+            }
+            prev = Some(c);
+        }
+        out
+    }
+
+    pub(crate) fn round(&self, precision: usize) -> Self {
+        // Rounds every real coefficient, the mixed parts included; terms rounded to zero are dropped.
+        let k = 10_f64.powi(precision as i32);
+        let round_vec = |v: &Option<Vec<Self>>| v.as_ref().map(|v| {
+            let terms = v.iter().map(|e| e.round(precision)).collect::<Vec<Self>>();
+            let non_zero = terms.iter().filter(|e| !(e.is_simple() && e.real == 0.0)).cloned().collect::<Vec<Self>>();
+            if non_zero.is_empty() { terms } else { non_zero }
+        });
+        let real = (self.real * k).round() / k;
+        let res = Self {
+            real: if real == 0.0 { 0.0 } else { real },
+            im_pow: self.im_pow,
+            mixed_base: round_vec(&self.mixed_base),
+            mixed_pow: round_vec(&self.mixed_pow),
+            mixed_mul: round_vec(&self.mixed_mul),
+        };
+        // A sum reduced to one term is the term itself.
+        if let Some(b) = &res.mixed_base && b.len() == 1 && res.mixed_pow.is_none() && res.mixed_mul.is_none() {
+            return b[0].clone()
+        }
+        res
+    }
+
     pub(crate) fn format(&self) -> String {
         if self.is_none() { return "None".to_string() }
 
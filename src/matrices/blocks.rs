@@ -0,0 +1,178 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Scalar;
+
+
+impl<T: Scalar> Matrix<T> {
+    pub fn kron(&self, rhs: &Self) -> Self {
+        //! Kronecker product, every element a of the matrix is replaced by the block a * rhs.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(2, 2, vec![
+        //!     1.0, 2.0,
+        //!     3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(1, 2, vec![
+        //!     1.0, -1.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m1 x m2 = {:?}", m1.kron(&m2));
+        //! // m1 x m2 = Matrix { strings: 2, rows: 4, body: [1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0, -4.0] }
+        //! ```
+
+        let (strings, rows) = (self.strings * rhs.strings, self.rows * rhs.rows);
+        let mut body = Vec::<T>::with_capacity(strings * rows);
+
+        for s in 0..strings {
+            let (s1, s2) = (s / rhs.strings, s % rhs.strings);
+            for r in 0..rows {
+                let (r1, r2) = (r / rhs.rows, r % rhs.rows);
+                body.push(self.body[s1 * self.rows + r1].clone() * rhs.body[s2 * rhs.rows + r2].clone());
+            }
+        }
+        Self::new(strings, rows, body).unwrap()
+    }
+
+    pub fn hadamard(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Hadamard (element-wise) product.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(2, 2, vec![
+        //!     1.0, 2.0,
+        //!     3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(2, 2, vec![
+        //!     5.0, 6.0,
+        //!     7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m1 o m2 = {:?}", m1.hadamard(&m2).unwrap());
+        //! // m1 o m2 = Matrix { strings: 2, rows: 2, body: [5.0, 12.0, 21.0, 32.0] }
+        //! ```
+
+        self._check_same_dimensions(rhs)?;
+
+        let new_m: Vec<T> = self.body
+            .iter()
+            .zip(&rhs.body)
+            .map(|(a, b)| a.clone() * b.clone())
+            .collect();
+
+        Self::new(self.strings, self.rows, new_m)
+    }
+
+    pub fn hstack(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Horizontal concatenation [self rhs], the matrices must have the same number of strings.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(2, 1, vec![
+        //!     1.0,
+        //!     2.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(2, 2, vec![
+        //!     3.0, 4.0,
+        //!     5.0, 6.0,
+        //! ]).unwrap();
+        //!
+        //! println!("[m1 m2] = {:?}", m1.hstack(&m2).unwrap());
+        //! // [m1 m2] = Matrix { strings: 2, rows: 3, body: [1.0, 3.0, 4.0, 2.0, 5.0, 6.0] }
+        //! ```
+
+        if self.strings != rhs.strings {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (rhs.strings, rhs.rows) })
+        }
+
+        let new_m: Vec<T> = self.body
+            .chunks(self.rows)
+            .zip(rhs.body.chunks(rhs.rows))
+            .flat_map(|(a, b)| a.iter().chain(b).cloned())
+            .collect();
+
+        Self::new(self.strings, self.rows + rhs.rows, new_m)
+    }
+
+    pub fn vstack(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Vertical concatenation [self; rhs], the matrices must have the same number of rows.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(1, 2, vec![
+        //!     1.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(2, 2, vec![
+        //!     3.0, 4.0,
+        //!     5.0, 6.0,
+        //! ]).unwrap();
+        //!
+        //! println!("[m1; m2] = {:?}", m1.vstack(&m2).unwrap());
+        //! // [m1; m2] = Matrix { strings: 3, rows: 2, body: [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] }
+        //! ```
+
+        if self.rows != rhs.rows {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (rhs.strings, rhs.rows) })
+        }
+
+        let new_m: Vec<T> = self.body
+            .iter()
+            .chain(&rhs.body)
+            .cloned()
+            .collect();
+
+        Self::new(self.strings + rhs.strings, self.rows, new_m)
+    }
+
+    pub fn from_blocks(blocks: &[Vec<Self>]) -> Result<Self, MatrixError> {
+        //! Block matrix. Blocks of a block string must have the same number of strings,
+        //! block strings must have the same number of rows.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let a = Matrix::new(2, 2, vec![
+        //!     1.0, 2.0,
+        //!     3.0, 4.0,
+        //! ]).unwrap();
+        //! let b = Matrix::new(2, 1, vec![5.0, 6.0]).unwrap();
+        //! let c = Matrix::new(1, 2, vec![7.0, 8.0]).unwrap();
+        //! let d = Matrix::new(1, 1, vec![9.0]).unwrap();
+        //!
+        //! let m = Matrix::from_blocks(&[
+        //!     vec![a, b],
+        //!     vec![c, d],
+        //! ]).unwrap();
+        //!
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 3, rows: 3, body: [1.0, 2.0, 5.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0] }
+        //! ```
+
+        let mut block_strings = Vec::<Self>::new();
+        for (i, str) in blocks.iter().enumerate() {
+            let (first, others) = str
+                .split_first()
+                .ok_or(MatrixError::Empty)?;
+            let mut m = first.clone();
+            for (j, b) in others.iter().enumerate() {
+                m = m.hstack(b).map_err(|_| MatrixError::BlockMismatch { block: (i+1, j+2), expected: m.strings, found: b.strings })?;
+            }
+            block_strings.push(m);
+        }
+
+        let (first, others) = block_strings.split_first().ok_or(MatrixError::Empty)?;
+        let mut m = first.clone();
+        for (i, str) in others.iter().enumerate() {
+            m = m.vstack(str).map_err(|_| MatrixError::BlockMismatch { block: (i+2, 0), expected: m.rows, found: str.rows })?;
+        }
+        Ok(m)
+    }
+}
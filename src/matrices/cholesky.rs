@@ -0,0 +1,138 @@
+use crate::matrices::{noise_level, Matrix};
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+use crate::vector_algebra::Vector;
+
+
+impl<T: Real> Matrix<T> {
+    pub fn cholesky(&self) -> Result<Self, MatrixError> {
+        //! Cholesky decomposition of a symmetric positive definite matrix: A = L*L^T.
+        //! Returns the lower triangular matrix L.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     4.0, 12.0, -16.0,
+        //!     12.0, 37.0, -43.0,
+        //!     -16.0, -43.0, 98.0,
+        //! ]).unwrap();
+        //!
+        //! println!("L = {:?}", m.cholesky().unwrap());
+        //! // L = Matrix { strings: 3, rows: 3, body: [2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0] }
+        //! ```
+
+        self._f64()._cholesky().map(T::matrix_from_f64)
+    }
+
+    pub fn cholesky_solve(&self, d: &[T]) -> Result<Vector<T>, MatrixError> {
+        //! Solves A*x = d, where self is the L factor of A from [Matrix::cholesky].
+        //! The factor can be reused for any number of d.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     4.0, 2.0,
+        //!     2.0, 3.0,
+        //! ]).unwrap();
+        //! let l = m.cholesky().unwrap();
+        //!
+        //! println!("x = {:.4}", l.cholesky_solve(&[6.0, 5.0]).unwrap());
+        //! // x = Vector (2) = [1.0000, 1.0000]
+        //! println!("x = {:.4}", l.cholesky_solve(&[2.0, 3.0]).unwrap());
+        //! // x = Vector (2) = [0.0000, 1.0000]
+        //! ```
+
+        self._f64()._cholesky_solve(&T::slice_to_f64(d)).map(|x| Vector::from(T::vec_from_f64(x)))
+    }
+
+    pub fn solve_spd(&self, d: &[Vec<T>]) -> Result<Vec<Vector<T>>, MatrixError> {
+        //! Solves A*x = d for every d of a symmetric positive definite matrix.
+        //! The matrix is factorized once (Cholesky decomposition).
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     4.0, 2.0,
+        //!     2.0, 3.0,
+        //! ]).unwrap();
+        //!
+        //! let d = vec![vec![6.0, 5.0], vec![2.0, 3.0]];
+        //!
+        //! for x in m.solve_spd(&d).unwrap() {
+        //!     println!("x = {:.4}", x);
+        //! }
+        //! // x = Vector (2) = [1.0000, 1.0000]
+        //! // x = Vector (2) = [0.0000, 1.0000]
+        //! ```
+
+        let l = self.cholesky()?;
+        d.iter()
+            .map(|e| l.cholesky_solve(e))
+            .collect()
+    }
+}
+
+
+impl Matrix {
+    fn _cholesky(&self) -> Result<Self, MatrixError> {
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+        if !self._is_symmetric_rel() {
+            return Err(MatrixError::NotSymmetric)
+        }
+
+        let n = self.rows;
+        let mut l = vec![0.0; n * n];
+
+        // Pivots smaller than this are rounding noise of a semidefinite matrix.
+        let tolerance = noise_level(n, &self.body);
+
+        for j in 0..n {
+            let pivot = self.body[j * n + j] - (0..j).map(|k| l[j * n + k] * l[j * n + k]).sum::<f64>();
+            if pivot <= tolerance || pivot.is_nan() {
+                return Err(MatrixError::NotPositiveDefinite { pivot: j+1 })
+            }
+            l[j * n + j] = pivot.sqrt();
+
+            for i in j+1..n {
+                let sum = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum::<f64>();
+                l[i * n + j] = (self.body[i * n + j] - sum) / l[j * n + j];
+            }
+        }
+
+        Self::new(n, n, l)
+    }
+
+    fn _cholesky_solve(&self, d: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+        if d.len() != self.strings {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (d.len(), 1) })
+        }
+
+        let n = self.rows;
+        let mut x = d.to_vec();
+
+        // L*y = d
+        for s in 0..n {
+            for r in 0..s {
+                x[s] -= self.body[s * n + r] * x[r];
+            }
+            x[s] /= self.body[s * n + s];
+        }
+
+        // L^T*x = y
+        for s in (0..n).rev() {
+            for r in s+1..n {
+                x[s] -= self.body[r * n + s] * x[r];
+            }
+            x[s] /= self.body[s * n + s];
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Scalar;
+
+
+impl<T: Scalar> Matrix<T> {
+    pub fn identity(n: usize) -> Result<Self, MatrixError> {
+        //! Identity matrix (n x n).
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! println!("E = {:?}", Matrix::<f64>::identity(2).unwrap());
+        //! // E = Matrix { strings: 2, rows: 2, body: [1.0, 0.0, 0.0, 1.0] }
+        //! ```
+
+        Self::from_fn(n, n, |s, r| if s == r { T::one() } else { T::zero() })
+    }
+
+    pub fn zeros(strings: usize, rows: usize) -> Result<Self, MatrixError> {
+        Self::new(strings, rows, vec![T::zero(); strings * rows])
+    }
+
+    pub fn ones(strings: usize, rows: usize) -> Result<Self, MatrixError> {
+        Self::new(strings, rows, vec![T::one(); strings * rows])
+    }
+
+    pub fn from_diagonal(diagonal: &[T]) -> Result<Self, MatrixError> {
+        //! Square matrix with the given diagonal.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! println!("D = {:?}", Matrix::from_diagonal(&[1.0, 2.0]).unwrap());
+        //! // D = Matrix { strings: 2, rows: 2, body: [1.0, 0.0, 0.0, 2.0] }
+        //! ```
+
+        let n = diagonal.len();
+        Self::from_fn(n, n, |s, r| if s == r { diagonal[s].clone() } else { T::zero() })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, MatrixError> {
+        //! Matrix from its strings.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::from_rows(vec![
+        //!     vec![1.0, 2.0, 3.0],
+        //!     vec![4.0, 5.0, 6.0],
+        //! ]).unwrap();
+        //!
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 2, rows: 3, body: [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] }
+        //! ```
+
+        let strings = rows.len();
+        let len = rows.first().map(|e| e.len()).unwrap_or(0);
+        if let Some(i) = rows.iter().position(|e| e.len() != len) {
+            return Err(MatrixError::InvalidArgument(format!("The string {} has {} elements instead of {}.", i+1, rows[i].len(), len)))
+        }
+
+        Self::new(strings, len, rows.into_iter().flatten().collect())
+    }
+
+    pub fn from_fn(strings: usize, rows: usize, f: impl Fn(usize, usize) -> T) -> Result<Self, MatrixError> {
+        //! Matrix with the elements f(s, r).
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::from_fn(2, 3, |s, r| (s * 10 + r) as f64).unwrap();
+        //!
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 2, rows: 3, body: [0.0, 1.0, 2.0, 10.0, 11.0, 12.0] }
+        //! ```
+
+        let body = (0..strings * rows)
+            .map(|i| f(i / rows, i % rows))
+            .collect();
+        Self::new(strings, rows, body)
+    }
+}
+
+
+impl Matrix {
+    pub fn hilbert(n: usize) -> Result<Self, MatrixError> {
+        //! Hilbert matrix, h[s, r] = 1 / (s + r + 1), a classic ill-conditioned matrix.
+
+        Self::from_fn(n, n, |s, r| 1.0 / (s + r + 1) as f64)
+    }
+
+    pub fn vandermonde(x: &[f64]) -> Result<Self, MatrixError> {
+        //! Vandermonde matrix, v[s, r] = x[s]^r.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! println!("V = {:?}", Matrix::vandermonde(&[1.0, 2.0, 3.0]).unwrap());
+        //! // V = Matrix { strings: 3, rows: 3, body: [1.0, 1.0, 1.0, 1.0, 2.0, 4.0, 1.0, 3.0, 9.0] }
+        //! ```
+
+        Self::from_fn(x.len(), x.len(), |s, r| x[s].powi(r as i32))
+    }
+
+    pub fn toeplitz(column: &[f64], row: &[f64]) -> Result<Self, MatrixError> {
+        //! Toeplitz matrix with the given first column and first row (string),
+        //! every diagonal is constant. column\[0\] is used for the main diagonal.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! println!("T = {:?}", Matrix::toeplitz(&[1.0, 2.0, 3.0], &[1.0, 4.0]).unwrap());
+        //! // T = Matrix { strings: 3, rows: 2, body: [1.0, 4.0, 2.0, 1.0, 3.0, 2.0] }
+        //! ```
+
+        Self::from_fn(column.len(), row.len(), |s, r| if s >= r { column[s - r] } else { row[r - s] })
+    }
+
+    pub fn circulant(column: &[f64]) -> Result<Self, MatrixError> {
+        //! Circulant matrix with the given first column, every column is the previous one shifted down.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! println!("C = {:?}", Matrix::circulant(&[1.0, 2.0, 3.0]).unwrap());
+        //! // C = Matrix { strings: 3, rows: 3, body: [1.0, 3.0, 2.0, 2.0, 1.0, 3.0, 3.0, 2.0, 1.0] }
+        //! ```
+
+        let n = column.len();
+        Self::from_fn(n, n, |s, r| column[(n + s - r) % n])
+    }
+}
@@ -0,0 +1,581 @@
+use crate::im::core::Im;
+use crate::im::im_matrices::ImMatrix;
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+use crate::utils::AdvancedEQ;
+
+
+impl<T: Real> Matrix<T> {
+    pub fn is_symmetric(&self, precision: f64) -> bool {
+        //! Returns True if the matrix is square and A = A^T within the precision, False otherwise.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     2.0, 1.0,
+        //!     1.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! assert!(m.is_symmetric(0.0001));
+        //! ```
+
+        if self.rows != self.strings { return false }
+
+        (0..self.strings).all(|s|
+            (s+1..self.rows).all(|r|
+                self.body[s * self.rows + r].to_f64().is_equal(self.body[r * self.rows + s].to_f64(), precision)
+            )
+        )
+    }
+
+    pub fn eigen_sym(&self) -> Result<(Vec<T>, Self), MatrixError> {
+        //! Eigenvalues and eigenvectors of a symmetric matrix (Jacobi eigenvalue algorithm).
+        //! Returns the eigenvalues in ascending order and the matrix whose columns are
+        //! the corresponding orthonormal eigenvectors.
+        //! The symmetry is checked relative to the largest element, NotConverged is returned
+        //! if the off-diagonal norm does not vanish in 100 sweeps.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     2.0, 1.0,
+        //!     1.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! let (values, vectors) = m.eigen_sym().unwrap();
+        //!
+        //! println!("values = {:.4?}", values);
+        //! // values = [1.0000, 3.0000]
+        //! println!("vectors = {:.4?}", vectors);
+        //! // vectors = Matrix { strings: 2, rows: 2, body: [0.7071, 0.7071, -0.7071, 0.7071] }
+        //! ```
+
+        let (values, vectors) = self._f64()._eigen_sym()?;
+        Ok((T::vec_from_f64(values), T::matrix_from_f64(vectors)))
+    }
+}
+
+
+impl Matrix {
+    pub(crate) fn _is_symmetric_rel(&self) -> bool {
+        // Symmetric up to rounding, relative to the largest element.
+        let max = self.body.iter().fold(0.0_f64, |m, e| m.max(e.abs()));
+        self.is_symmetric(f64::EPSILON.sqrt() * max)
+    }
+
+    fn _eigen_sym(&self) -> Result<(Vec<f64>, Self), MatrixError> {
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+        if !self._is_symmetric_rel() {
+            return Err(MatrixError::NotSymmetric)
+        }
+
+        let n = self.rows;
+        // (A + A^T) / 2 removes the rounding asymmetry, which the rotations can not annihilate.
+        let a_t = self.transpose();
+        let mut a = self.body.iter().zip(&a_t.body).map(|(x, y)| 0.5 * (x + y)).collect::<Vec<f64>>();
+        let mut v = vec![0.0; n * n];
+        for i in 0..n { v[i * n + i] = 1.0 }
+
+        let norm = a.iter().map(|e| e * e).sum::<f64>().sqrt();
+        let tolerance = f64::EPSILON * norm;
+        let off = |a: &[f64]| (0..n)
+            .flat_map(|s| (0..n).filter(move |r| *r != s).map(move |r| (s, r)))
+            .map(|(s, r)| a[s * n + r] * a[s * n + r])
+            .sum::<f64>()
+            .sqrt();
+
+        let mut sweeps = 0;
+        while off(&a) > tolerance {
+            if sweeps == 100 { return Err(MatrixError::NotConverged) }
+            sweeps += 1;
+
+            for p in 0..n {
+                for q in p+1..n {
+                    let apq = a[p * n + q];
+                    if apq.abs() <= f64::MIN_POSITIVE { continue }
+
+                    let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    // A = J^T * A * J
+                    for k in 0..n {
+                        let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * akp - s * akq;
+                        a[k * n + q] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * apk - s * aqk;
+                        a[q * n + k] = s * apk + c * aqk;
+                    }
+                    a[p * n + q] = 0.0;
+                    a[q * n + p] = 0.0;
+                    // V = V * J
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * vkp - s * vkq;
+                        v[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order = (0..n).collect::<Vec<usize>>();
+        order.sort_by(|i, j| a[i * n + i].total_cmp(&a[j * n + j]));
+
+        let values = order.iter().map(|i| a[i * n + i]).collect();
+        let mut vectors = vec![0.0; n * n];
+        for (c, i) in order.iter().enumerate() {
+            // The sign of an eigenvector is arbitrary, the largest component is made positive.
+            let max = (0..n)
+                .map(|k| v[k * n + i])
+                .fold(0.0_f64, |m, e| if e.abs() > m.abs() { e } else { m });
+            let sign = if max < 0.0 { -1.0 } else { 1.0 };
+            for k in 0..n {
+                vectors[k * n + c] = sign * v[k * n + i]
+            }
+        }
+
+        Ok((values, Self::new(n, n, vectors)?))
+    }
+}
+
+
+pub(crate) type Complex = (f64, f64);
+
+pub(crate) fn c_mul(a: Complex, b: Complex) -> Complex {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+pub(crate) fn c_div(a: Complex, b: Complex) -> Complex {
+    let d = b.0 * b.0 + b.1 * b.1;
+    ((a.0 * b.0 + a.1 * b.1) / d, (a.1 * b.0 - a.0 * b.1) / d)
+}
+
+pub(crate) fn c_abs(a: Complex) -> f64 {
+    a.0.hypot(a.1)
+}
+
+pub(crate) fn to_im(a: Complex) -> Im {
+    match (a.0 == 0.0, a.1 == 0.0) {
+        (_, true) => Im::new(a.0, 0.0),
+        (true, false) => Im::new(a.1, 1.0),
+        _ => Im::new(a.0, 0.0) + Im::new(a.1, 1.0),
+    }
+}
+
+pub(crate) fn from_im(e: &Im) -> Option<Complex> {
+    // real * i^im_pow and sums of them, None for the symbolic values (mixed powers and products).
+    if e.is_simple() {
+        let p = e.im_pow.rem_euclid(4.0);
+        return Some(
+            if p == 0.0 { (e.real, 0.0) }
+            else if p == 1.0 { (0.0, e.real) }
+            else if p == 2.0 { (-e.real, 0.0) }
+            else if p == 3.0 { (0.0, -e.real) }
+            else {
+                let a = p * std::f64::consts::FRAC_PI_2;
+                (e.real * a.cos(), e.real * a.sin())
+            }
+        )
+    }
+    if e.is_mixed_base_only() && !e.is_none() {
+        return e.mixed_base.as_ref()?
+            .iter()
+            .map(from_im)
+            .try_fold((0.0, 0.0), |acc, c| c.map(|c| (acc.0 + c.0, acc.1 + c.1)))
+    }
+    None
+}
+
+
+impl<T: Real> Matrix<T> {
+    pub fn eigenvalues(&self) -> Result<Vec<Im>, MatrixError> {
+        //! Eigenvalues of a general (non-symmetric) matrix (Hessenberg reduction and shifted QR algorithm).
+        //! Complex eigenvalues come in conjugate pairs.
+        //! The eigenvalues are sorted by the real part, then by the imaginary part.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, -2.0,
+        //!     2.0, 1.0,
+        //! ]).unwrap();
+        //!
+        //! for (i, e) in m.eigenvalues().unwrap().iter().enumerate() {
+        //!     println!("l{} = {}", i+1, e);
+        //! }
+        //! // l1 = (1-2i)
+        //! // l2 = (1+2i)
+        //! ```
+
+        Ok(self._f64()._eigenvalues()?
+            .into_iter()
+            .map(to_im)
+            .collect())
+    }
+
+    pub fn eigen(&self) -> Result<(Vec<Im>, ImMatrix), MatrixError> {
+        //! Eigenvalues and eigenvectors of a general (non-symmetric) matrix.
+        //! Returns the eigenvalues as in [Matrix::eigenvalues] and the matrix whose columns are
+        //! the corresponding eigenvectors (inverse iteration), normalized to unit length
+        //! with the largest component real and positive.
+        //! A defective matrix (a repeated eigenvalue without the full eigenspace) returns NotDiagonalizable.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     0.0, -1.0,
+        //!     1.0, 0.0,
+        //! ]).unwrap();
+        //!
+        //! let (values, vectors) = m.eigen().unwrap();
+        //!
+        //! println!("l1 = {}, l2 = {}", values[0], values[1]);
+        //! // l1 = -i, l2 = i
+        //! let (v1, v2) = (vectors.col(0), vectors.col(1));
+        //! println!("v1 = ({:.4}, {:.4}), v2 = ({:.4}, {:.4})", v1[0], v1[1], v2[0], v2[1]);
+        //! // v1 = (0.7071, 0.7071i), v2 = (0.7071, -0.7071i)
+        //! ```
+
+        self._f64()._eigen()
+    }
+}
+
+
+impl Matrix {
+    fn _eigen(&self) -> Result<(Vec<Im>, ImMatrix), MatrixError> {
+        let values = self._eigenvalues()?;
+        let n = self.rows;
+
+        let mut vectors = vec![Im::default(); n * n];
+        for (c, v) in self._eigenvectors(&values)?.into_iter().enumerate() {
+            for (s, e) in v.into_iter().enumerate() {
+                vectors[s * n + c] = to_im(e)
+            }
+        }
+
+        Ok((
+            values.into_iter().map(to_im).collect(),
+            ImMatrix::new(n, n, vectors)?
+        ))
+    }
+
+    pub(crate) fn _eigenvalues(&self) -> Result<Vec<Complex>, MatrixError> {
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+
+        let mut a = self._hessenberg();
+        let mut values = hqr(&mut a, self.rows)
+            .ok_or(MatrixError::NotConverged)?;
+
+        values.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        Ok(values)
+    }
+
+    fn _hessenberg(&self) -> Vec<f64> {
+        // Gaussian elimination with pivoting, similarity transformations only.
+        let n = self.rows;
+        let mut a = self.body.to_vec();
+
+        for m in 1..n.saturating_sub(1) {
+            let mut x = 0.0_f64;
+            let mut i = m;
+            for j in m..n {
+                if a[j * n + m-1].abs() > x.abs() {
+                    x = a[j * n + m-1];
+                    i = j;
+                }
+            }
+            if i != m {
+                for j in m-1..n { a.swap(i * n + j, m * n + j) }
+                for j in 0..n { a.swap(j * n + i, j * n + m) }
+            }
+            if x != 0.0 {
+                for i in m+1..n {
+                    let mut y = a[i * n + m-1];
+                    if y == 0.0 { continue }
+                    y /= x;
+                    for j in m..n { a[i * n + j] -= y * a[m * n + j] }
+                    for j in 0..n { a[j * n + m] += y * a[j * n + i] }
+                }
+            }
+            for i in m+1..n { a[i * n + m-1] = 0.0 }
+        }
+        a
+    }
+
+    pub(crate) fn _eigenvectors(&self, values: &[Complex]) -> Result<Vec<Vec<Complex>>, MatrixError> {
+        // Eigenvectors of every eigenvalue, the vectors of a repeated eigenvalue must be independent.
+        let scale = self.body.iter().fold(1.0_f64, |m, e| m.max(e.abs()));
+        let vectors = values
+            .iter()
+            .enumerate()
+            .map(|(k, l)| self._eigenvector(*l, k))
+            .collect::<Vec<Vec<Complex>>>();
+
+        let mut done = vec![false; values.len()];
+        for i in 0..values.len() {
+            if done[i] { continue }
+            let group = (i..values.len())
+                .filter(|j| c_abs((values[*j].0 - values[i].0, values[*j].1 - values[i].1)) <= 1e-6 * scale)
+                .collect::<Vec<usize>>();
+            group.iter().for_each(|j| done[*j] = true);
+            if group.len() > 1 && !independent(group.iter().map(|j| &vectors[*j])) {
+                return Err(MatrixError::NotDiagonalizable)
+            }
+        }
+        Ok(vectors)
+    }
+
+    fn _eigenvector(&self, l: Complex, k: usize) -> Vec<Complex> {
+        // Inverse iteration: (A - mu*E)*x_(i+1) = x_i, mu is a slightly perturbed eigenvalue.
+        let n = self.rows;
+        let scale = self.body.iter().fold(1.0_f64, |m, e| m.max(e.abs()));
+        let shift = 1e-10 * scale;
+        let mu = (l.0 + shift, l.1);
+
+        let mut m = self.body
+            .iter()
+            .map(|e| (*e, 0.0))
+            .collect::<Vec<Complex>>();
+        for i in 0..n {
+            m[i * n + i] = (m[i * n + i].0 - mu.0, -mu.1);
+        }
+
+        let mut x = (0..n)
+            .map(|i| (if i == k { 2.0 } else { 1.0 }, 0.0))
+            .collect::<Vec<Complex>>();
+        for _ in 0..3 {
+            x = c_solve(&m, n, &x);
+            let norm = x.iter().map(|e| c_abs(*e).powi(2)).sum::<f64>().sqrt();
+            x.iter_mut().for_each(|e| *e = (e.0 / norm, e.1 / norm));
+        }
+
+        // The largest component becomes real and positive.
+        // Components within the error of the shift (relative to the matrix) are noise.
+        let noise = 100.0 * shift / scale;
+        let max = x.iter().fold((0.0, 0.0), |m, e| if c_abs(*e) > c_abs(m) { *e } else { m });
+        let phase = c_div((c_abs(max), 0.0), max);
+        x.into_iter()
+            .map(|e| {
+                let (re, im) = c_mul(e, phase);
+                (
+                    if re.abs() < noise { 0.0 } else { re },
+                    if im.abs() < noise { 0.0 } else { im },
+                )
+            })
+            .collect()
+    }
+}
+
+fn independent<'a>(vectors: impl Iterator<Item = &'a Vec<Complex>>) -> bool {
+    // Gram-Schmidt: a vector (almost) in the span of the previous ones is dependent.
+    let mut basis = Vec::<Vec<Complex>>::new();
+    for v in vectors {
+        let mut w = v.clone();
+        for b in &basis {
+            let d = b.iter().zip(&w).fold((0.0, 0.0), |s, (b, w)| {
+                let p = c_mul((b.0, -b.1), *w);
+                (s.0 + p.0, s.1 + p.1)
+            });
+            for (w, b) in w.iter_mut().zip(b) {
+                let p = c_mul(d, *b);
+                *w = (w.0 - p.0, w.1 - p.1)
+            }
+        }
+        let norm = w.iter().map(|e| c_abs(*e).powi(2)).sum::<f64>().sqrt();
+        if norm < 1e-6 { return false }
+        basis.push(w.into_iter().map(|e| (e.0 / norm, e.1 / norm)).collect());
+    }
+    true
+}
+
+fn c_solve(m: &[Complex], n: usize, d: &[Complex]) -> Vec<Complex> {
+    let mut a = m.to_vec();
+    let mut x = d.to_vec();
+
+    for k in 0..n {
+        let p = (k..n).fold(k, |p, s| if c_abs(a[s * n + k]) > c_abs(a[p * n + k]) { s } else { p });
+        if p != k {
+            for r in 0..n { a.swap(k * n + r, p * n + r) }
+            x.swap(k, p);
+        }
+        if c_abs(a[k * n + k]) == 0.0 { a[k * n + k] = (f64::EPSILON, 0.0) }
+
+        for s in k+1..n {
+            let f = c_div(a[s * n + k], a[k * n + k]);
+            for r in k..n {
+                let e = c_mul(f, a[k * n + r]);
+                a[s * n + r] = (a[s * n + r].0 - e.0, a[s * n + r].1 - e.1);
+            }
+            let e = c_mul(f, x[k]);
+            x[s] = (x[s].0 - e.0, x[s].1 - e.1);
+        }
+    }
+
+    for s in (0..n).rev() {
+        for r in s+1..n {
+            let e = c_mul(a[s * n + r], x[r]);
+            x[s] = (x[s].0 - e.0, x[s].1 - e.1);
+        }
+        x[s] = c_div(x[s], a[s * n + s]);
+    }
+    x
+}
+
+fn hqr(a: &mut [f64], n: usize) -> Option<Vec<Complex>> {
+    // Eigenvalues of an upper Hessenberg matrix (Francis double shift QR).
+    let at = |i: isize, j: isize| (i as usize) * n + j as usize;
+    let mut values = vec![(0.0, 0.0); n];
+
+    let mut anorm = 0.0;
+    for i in 0..n {
+        for j in i.saturating_sub(1)..n {
+            anorm += a[i * n + j].abs();
+        }
+    }
+
+    let mut nn = n as isize - 1;
+    let mut t = 0.0;
+    while nn >= 0 {
+        let mut its = 0;
+        loop {
+            let mut l = nn;
+            while l >= 1 {
+                let mut s = a[at(l-1, l-1)].abs() + a[at(l, l)].abs();
+                if s == 0.0 { s = anorm }
+                if a[at(l, l-1)].abs() + s == s {
+                    a[at(l, l-1)] = 0.0;
+                    break
+                }
+                l -= 1;
+            }
+
+            let mut x = a[at(nn, nn)];
+            if l == nn {
+                // one root
+                values[nn as usize] = (x + t, 0.0);
+                nn -= 1;
+                break
+            }
+
+            let mut y = a[at(nn-1, nn-1)];
+            let mut w = a[at(nn, nn-1)] * a[at(nn-1, nn)];
+            if l == nn - 1 {
+                // two roots
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    let second = if z != 0.0 { x - w / z } else { x + z };
+                    values[nn as usize - 1] = (x + z, 0.0);
+                    values[nn as usize] = (second, 0.0);
+                } else {
+                    values[nn as usize - 1] = (x + p, -z);
+                    values[nn as usize] = (x + p, z);
+                }
+                nn -= 2;
+                break
+            }
+
+            if its == 60 { return None }
+            if its == 10 || its == 20 {
+                // exceptional shift
+                t += x;
+                for i in 0..=nn { a[at(i, i)] -= x }
+                let s = a[at(nn, nn-1)].abs() + a[at(nn-1, nn-2)].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+
+            let (mut p, mut q, mut r) = (0.0, 0.0, 0.0);
+            let mut m = nn - 2;
+            while m >= l {
+                let z = a[at(m, m)];
+                r = x - z;
+                let s = y - z;
+                p = (r * s - w) / a[at(m+1, m)] + a[at(m, m+1)];
+                q = a[at(m+1, m+1)] - z - r - s;
+                r = a[at(m+2, m+1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l { break }
+                let u = a[at(m, m-1)].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[at(m-1, m-1)].abs() + z.abs() + a[at(m+1, m+1)].abs());
+                if u + v == v { break }
+                m -= 1;
+            }
+
+            for i in m+2..=nn {
+                a[at(i, i-2)] = 0.0;
+                if i != m + 2 { a[at(i, i-3)] = 0.0 }
+            }
+
+            for k in m..nn {
+                if k != m {
+                    p = a[at(k, k-1)];
+                    q = a[at(k+1, k-1)];
+                    r = if k != nn - 1 { a[at(k+2, k-1)] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 { continue }
+
+                if k == m {
+                    if l != m { a[at(k, k-1)] = -a[at(k, k-1)] }
+                } else {
+                    a[at(k, k-1)] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..=nn {
+                    let mut p = a[at(k, j)] + q * a[at(k+1, j)];
+                    if k != nn - 1 {
+                        p += r * a[at(k+2, j)];
+                        a[at(k+2, j)] -= p * z;
+                    }
+                    a[at(k+1, j)] -= p * y;
+                    a[at(k, j)] -= p * x;
+                }
+
+                for i in l..=nn.min(k + 3) {
+                    let mut p = x * a[at(i, k)] + y * a[at(i, k+1)];
+                    if k != nn - 1 {
+                        p += z * a[at(i, k+2)];
+                        a[at(i, k+2)] -= p * r;
+                    }
+                    a[at(i, k+1)] -= p * q;
+                    a[at(i, k)] -= p;
+                }
+            }
+        }
+    }
+    Some(values)
+}
@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+
+/// Errors of the matrix operations. Shapes are (strings, rows).
+/// # Example
+///```
+/// use xmath::matrices::Matrix;
+/// use xmath::matrices::error::MatrixError;
+///
+/// let m1 = Matrix::new(2, 3, vec![0.0; 6]).unwrap();
+/// let m2 = Matrix::new(2, 2, vec![0.0; 4]).unwrap();
+///
+/// match m1.add_by_ref(&m2) {
+///     Err(MatrixError::DimensionMismatch { left, right }) => println!("{:?} + {:?}", left, right),
+///     _ => unreachable!(),
+/// }
+/// // (2, 3) + (2, 2)
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixError {
+    /// Shapes of the operands do not fit the operation.
+    DimensionMismatch { left: (usize, usize), right: (usize, usize) },
+    /// The operation is only possible for square matrices.
+    NotSquare { strings: usize, rows: usize },
+    /// The matrix is singular (or numerically singular).
+    Singular,
+    /// Zero strings or rows, or nothing to build the matrix from.
+    Empty,
+    /// The block (block string, block), 1-based, has `found` strings instead of `expected`.
+    /// The block 0 is the whole block string, then `found` and `expected` are its rows.
+    BlockMismatch { block: (usize, usize), expected: usize, found: usize },
+    /// The element (s, r) is out of the matrix.
+    IndexOutOfBounds { index: (usize, usize), shape: (usize, usize) },
+    NotSymmetric,
+    /// The Cholesky decomposition pivot (1-based) is not positive.
+    NotPositiveDefinite { pivot: usize },
+    /// The conjugate gradient method found p^T*A*p <= 0 at the iteration (1-based),
+    /// the matrix is not positive definite.
+    NegativeCurvature { iteration: usize },
+    /// The eigenvalue (1-based, in ascending order) is not positive.
+    NonPositiveEigenvalue { index: usize },
+    /// The condition number exceeds the allowed limit.
+    IllConditioned { cond: f64 },
+    /// The iterative algorithm did not converge.
+    NotConverged,
+    /// A repeated eigenvalue has fewer independent eigenvectors than its multiplicity.
+    NotDiagonalizable,
+    /// Other invalid input, the message describes it.
+    InvalidArgument(String),
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DimensionMismatch { left, right } =>
+                write!(f, "Matrix dimensions do not match ({}x{} and {}x{}).", left.0, left.1, right.0, right.1),
+            Self::NotSquare { strings, rows } =>
+                write!(f, "The operation is only possible for square matrices, the matrix is {}x{}.", strings, rows),
+            Self::Singular => write!(f, "The matrix is singular."),
+            Self::Empty => write!(f, "The matrix is empty."),
+            Self::BlockMismatch { block: (s, 0), expected, found } =>
+                write!(f, "The block string {} has {} rows instead of {}.", s, found, expected),
+            Self::BlockMismatch { block, expected, found } =>
+                write!(f, "The block {} of the block string {} has {} strings instead of {}.", block.1, block.0, found, expected),
+            Self::IndexOutOfBounds { index, shape } =>
+                write!(f, "The index ({}, {}) is out of the matrix {}x{}.", index.0, index.1, shape.0, shape.1),
+            Self::NotSymmetric => write!(f, "The matrix is not symmetric."),
+            Self::NotPositiveDefinite { pivot } => write!(f, "The matrix is not positive definite (pivot {}).", pivot),
+            Self::NegativeCurvature { iteration } =>
+                write!(f, "The matrix is not positive definite (p^T*A*p <= 0 at the iteration {}).", iteration),
+            Self::NonPositiveEigenvalue { index } => write!(f, "The eigenvalue {} is not positive.", index),
+            Self::IllConditioned { cond } => write!(f, "The matrix is ill-conditioned (cond = {:.3e}).", cond),
+            Self::NotConverged => write!(f, "The algorithm did not converge."),
+            Self::NotDiagonalizable => write!(f, "The matrix is not diagonalizable."),
+            Self::InvalidArgument(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl Error for MatrixError {}
@@ -0,0 +1,135 @@
+use crate::matrices::Matrix;
+use crate::matrices::scalar::Scalar;
+
+
+impl<T: Scalar> Matrix<T> {
+    fn _texts(&self, text: impl Fn(&T) -> String) -> Vec<Vec<String>> {
+        self.body
+            .chunks(self.rows)
+            .map(|str| str.iter().map(&text).collect())
+            .collect()
+    }
+
+    pub fn pretty(&self, precision: Option<usize>, width: usize) -> String {
+        //! Column-aligned text of the matrix, every element takes at least width characters.
+        //! Display uses it, so the same is printed by "{:w.p}".
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, -2.5, 3.0,
+        //!     10.0, 0.0, -1.25,
+        //! ]).unwrap();
+        //!
+        //! println!("m = {}", m);
+        //! // m = Matrix (2x3) = [
+        //! //   1 -2.5     3
+        //! //  10    0 -1.25
+        //! // ]
+        //! println!("m = {:6.2}", m);
+        //! // m = Matrix (2x3) = [
+        //! //    1.00  -2.50   3.00
+        //! //   10.00   0.00  -1.25
+        //! // ]
+        //! ```
+
+        let texts = self._texts(|e| e.to_text(precision));
+        let widths = (0..self.rows)
+            .map(|r| texts.iter().map(|str| str[r].chars().count()).fold(width, usize::max))
+            .collect::<Vec<usize>>();
+
+        let mut out = format!("Matrix ({}x{}) = [\n", self.strings, self.rows);
+        for str in texts {
+            for (e, w) in str.iter().zip(&widths) {
+                out += &format!(" {:>w$}", e, w = w);
+            }
+            out += "\n";
+        }
+        out + "]"
+    }
+
+    pub fn to_latex(&self, precision: Option<usize>) -> String {
+        //! LaTeX bmatrix environment.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 0.5,
+        //!     -3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! println!("{}", m.to_latex(Some(1)));
+        //! // \begin{bmatrix}
+        //! // 1.0 & 0.5 \\
+        //! // -3.0 & 4.0
+        //! // \end{bmatrix}
+        //! ```
+
+        let strings = self._texts(|e| e.to_text(precision))
+            .iter()
+            .map(|str| str.join(" & "))
+            .collect::<Vec<String>>()
+            .join(" \\\\\n");
+        format!("\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}", strings)
+    }
+
+    pub fn to_markdown(&self, precision: Option<usize>) -> String {
+        //! Markdown table, the header holds the numbers of the rows (columns).
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 0.5,
+        //!     -3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! println!("{}", m.to_markdown(None));
+        //! // | 1 | 2 |
+        //! // |---:|---:|
+        //! // | 1 | 0.5 |
+        //! // | -3 | 4 |
+        //! ```
+
+        let mut out = String::new();
+        out += &format!("|{}\n", (1..=self.rows).map(|r| format!(" {} |", r)).collect::<String>());
+        out += &format!("|{}\n", "---:|".repeat(self.rows));
+        for str in self._texts(|e| e.to_text(precision)) {
+            out += &format!("|{}\n", str.iter().map(|e| format!(" {} |", e)).collect::<String>());
+        }
+        out.pop();
+        out
+    }
+
+    pub fn to_numpy(&self, precision: Option<usize>) -> String {
+        //! NumPy array literal; imaginary numbers are written as Python complex expressions.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::im::im_matrices::ImMatrix;
+        //! use xmath::im::cast::ImValue;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 0.5,
+        //!     -3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! println!("{}", m.to_numpy(None));
+        //! // np.array([[1.0, 0.5], [-3.0, 4.0]])
+        //!
+        //! let m = ImMatrix::new(1, 2, vec![1.r() + 2.i(), (-1).i()]).unwrap();
+        //!
+        //! println!("{}", m.to_numpy(None));
+        //! // np.array([[(1+2j), -1j]])
+        //! ```
+
+        let strings = self._texts(|e| e.to_python(precision))
+            .iter()
+            .map(|str| format!("[{}]", str.join(", ")))
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!("np.array([{}])", strings)
+    }
+}
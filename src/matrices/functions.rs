@@ -0,0 +1,228 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+
+
+fn solve(a: &Matrix, b: &Matrix) -> Option<Matrix> {
+    // A*X = B column by column.
+    let lu = a._lu();
+    if lu.is_singular { return None }
+
+    let mut body = vec![0.0; b.body.len()];
+    for r in 0..b.rows {
+        for (s, x) in lu.solve(&b.col(r)).iter().enumerate() {
+            body[s * b.rows + r] = *x
+        }
+    }
+    Matrix::new(b.strings, b.rows, body).ok()
+}
+
+
+impl<T: Real> Matrix<T> {
+    pub fn expm(&self) -> Result<Self, MatrixError> {
+        //! Matrix exponential, e^A (scaling and squaring with the Pade approximant of degree 6).
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! // x' = A*x, x(t) = e^(A*t) * x(0)
+        //! let m = Matrix::new(2, 2, vec![
+        //!     0.0, 1.0,
+        //!     -1.0, 0.0,
+        //! ]).unwrap();
+        //!
+        //! println!("e^A = {:.4?}", m.expm().unwrap());
+        //! // e^A = Matrix { strings: 2, rows: 2, body: [0.5403, 0.8415, -0.8415, 0.5403] }
+        //! ```
+
+        self._f64()._expm().map(T::matrix_from_f64)
+    }
+
+    pub fn sqrtm(&self) -> Result<Self, MatrixError> {
+        //! Principal matrix square root, X*X = A (Denman-Beavers iteration).
+        //! The matrix must not have eigenvalues on the closed negative real axis.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     33.0, 24.0,
+        //!     48.0, 57.0,
+        //! ]).unwrap();
+        //!
+        //! println!("sqrt(A) = {:.4?}", m.sqrtm().unwrap());
+        //! // sqrt(A) = Matrix { strings: 2, rows: 2, body: [5.0000, 2.0000, 4.0000, 7.0000] }
+        //! ```
+
+        self._f64()._sqrtm().map(T::matrix_from_f64)
+    }
+
+    pub fn logm(&self) -> Result<Self, MatrixError> {
+        //! Principal matrix logarithm, e^X = A (inverse scaling and squaring).
+        //! The matrix must not have eigenvalues on the closed negative real axis.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 1.0,
+        //!     0.0, 1.0,
+        //! ]).unwrap();
+        //!
+        //! println!("log(A) = {:.4?}", m.logm().unwrap());
+        //! // log(A) = Matrix { strings: 2, rows: 2, body: [0.0000, 1.0000, 0.0000, 0.0000] }
+        //! ```
+
+        self._f64()._logm().map(T::matrix_from_f64)
+    }
+
+    pub fn powf(&self, pow: f64) -> Result<Self, MatrixError> {
+        //! Matrix power with a real exponent, A^p = V * diag(l^p) * V^-1 (eigendecomposition).
+        //! Integer exponents are passed to [Matrix::powi]. Otherwise the matrix must be
+        //! symmetric positive definite, or diagonalizable with positive real eigenvalues.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     5.0, 4.0,
+        //!     4.0, 5.0,
+        //! ]).unwrap();
+        //!
+        //! println!("A^0.5 = {:.4?}", m.powf(0.5).unwrap());
+        //! // A^0.5 = Matrix { strings: 2, rows: 2, body: [2.0000, 1.0000, 1.0000, 2.0000] }
+        //! ```
+
+        self._f64()._powf(pow).map(T::matrix_from_f64)
+    }
+}
+
+
+impl Matrix {
+    fn _expm(&self) -> Result<Self, MatrixError> {
+        if self.rows != self.strings { return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows }) }
+        if self.body.iter().any(|e| !e.is_finite()) { return Err(MatrixError::InvalidArgument("The matrix has non-finite elements.".to_string())) }
+
+        // e^A = (e^(A / 2^s))^(2^s), where |A / 2^s| <= 0.5
+        let norm = self._norm_1();
+        let s = if norm > 0.5 { (norm / 0.5).log2().ceil() as i32 } else { 0 };
+        let a = self.mul_num(1.0 / 2_f64.powi(s));
+
+        // N(A) = sum(c[k] * A^k), D(A) = N(-A)
+        let q = 6;
+        let mut c = 1.0;
+        let mut x = Self::identity(self.rows)?;
+        let mut n = Self::identity(self.rows)?;
+        let mut d = Self::identity(self.rows)?;
+        for k in 1..=q {
+            c *= (q - k + 1) as f64 / (k * (2 * q - k + 1)) as f64;
+            x = x.mul_by_ref(&a)?;
+            n = n.add_by_ref(&x.mul_num(c))?;
+            d = d.add_by_ref(&x.mul_num(if k % 2 == 0 { c } else { -c }))?;
+        }
+
+        let mut f = solve(&d, &n).ok_or(MatrixError::Singular)?;
+        for _ in 0..s {
+            f = f.mul_by_ref(&f)?;
+        }
+        Ok(f)
+    }
+
+    fn _sqrtm(&self) -> Result<Self, MatrixError> {
+        if self.rows != self.strings { return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows }) }
+
+        let mut y = self.clone();
+        let mut z = Self::identity(self.rows)?;
+
+        for _ in 0..100 {
+            let y_inv = y.inverse().ok_or(MatrixError::Singular)?;
+            let z_inv = z.inverse().ok_or(MatrixError::Singular)?;
+            let y_next = y.add_by_ref(&z_inv)?.mul_num(0.5);
+            z = z.add_by_ref(&y_inv)?.mul_num(0.5);
+
+            let diff = y_next.sub_by_ref(&y)?._norm_1();
+            y = y_next;
+            if !diff.is_finite() { break }
+            // The convergence is quadratic, so the error of y is about diff^2.
+            if diff <= f64::EPSILON.sqrt() * y._norm_1() {
+                return Ok(y)
+            }
+        }
+        Err(MatrixError::NotConverged)
+    }
+
+    fn _logm(&self) -> Result<Self, MatrixError> {
+        if self.rows != self.strings { return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows }) }
+
+        // log(A) = 2^k * log(A^(1 / 2^k)), where |A^(1 / 2^k) - E| < 0.25
+        let e = Self::identity(self.rows)?;
+        let mut a = self.clone();
+        let mut k = 0;
+        while a.sub_by_ref(&e)?._norm_1() >= 0.25 {
+            if k == 64 { return Err(MatrixError::NotConverged) }
+            a = a.sqrtm()?;
+            k += 1;
+        }
+
+        // log(A) = 2 * sum(Z^j / j), j = 1, 3, 5, ..., where Z = (A - E) * (A + E)^-1
+        let z = solve(&a.add_by_ref(&e)?.transpose(), &a.sub_by_ref(&e)?.transpose())
+            .ok_or(MatrixError::Singular)?
+            .transpose();
+        let z2 = z.mul_by_ref(&z)?;
+        let mut term = z.clone();
+        let mut log = z;
+        for j in (3..).step_by(2) {
+            term = term.mul_by_ref(&z2)?;
+            let add = term.mul_num(1.0 / j as f64);
+            log = log.add_by_ref(&add)?;
+            if add._norm_1() <= f64::EPSILON * log._norm_1() || j > 200 { break }
+        }
+
+        Ok(log.mul_num(2.0 * 2_f64.powi(k)))
+    }
+
+    fn _powf(&self, pow: f64) -> Result<Self, MatrixError> {
+        if self.rows != self.strings { return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows }) }
+        if !pow.is_finite() { return Err(MatrixError::InvalidArgument("The exponent must be finite.".to_string())) }
+        if pow.fract() == 0.0 && pow.abs() < i64::MAX as f64 {
+            return self.powi(pow as i64)
+        }
+
+        let n = self.rows;
+        let (values, v, v_inv) = if self._is_symmetric_rel() {
+            let (values, v) = self.eigen_sym()?;
+            if let Some(i) = values.iter().position(|l| *l <= 0.0) {
+                return Err(MatrixError::NonPositiveEigenvalue { index: i+1 })
+            }
+            let v_inv = v.transpose();
+            (values, v, v_inv)
+        } else {
+            let values = self._eigenvalues()?;
+            if values.iter().any(|l| l.1 != 0.0) {
+                return Err(MatrixError::InvalidArgument("The matrix has complex eigenvalues.".to_string()))
+            }
+            if let Some(i) = values.iter().position(|l| l.0 <= 0.0) {
+                return Err(MatrixError::NonPositiveEigenvalue { index: i+1 })
+            }
+            let mut body = vec![0.0; n * n];
+            for (c, v) in self._eigenvectors(&values)?.into_iter().enumerate() {
+                for (s, e) in v.into_iter().enumerate() {
+                    body[s * n + c] = e.0
+                }
+            }
+            let v = Self::new(n, n, body)?;
+            // Ill-conditioned eigenvectors mean a (nearly) defective matrix.
+            let v_inv = v.inverse_checked(1e12).map_err(|_| MatrixError::NotDiagonalizable)?;
+            (values.into_iter().map(|l| l.0).collect(), v, v_inv)
+        };
+
+        // V * diag(l^p) scales the columns of V.
+        let mut body = v.body;
+        for s in 0..n {
+            for (r, l) in values.iter().enumerate() {
+                body[s * n + r] *= l.powf(pow)
+            }
+        }
+        Self::new(n, n, body)?.mul_by_ref(&v_inv)
+    }
+}
@@ -0,0 +1,510 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::str::FromStr;
+use crate::im::cast::ImValue;
+use crate::im::core::Im;
+use crate::im::im_matrices::ImMatrix;
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Scalar;
+use crate::matrices::sparse::{CooBuilder, SparseMatrix};
+
+
+/// Error of the text input, line and column start from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+impl ParseError {
+    fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self { line, column, message: message.into() }
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (line {}, column {})", self.message, self.line, self.column)
+    }
+}
+impl Error for ParseError {}
+
+/// Error of the CSV and Matrix Market readers.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The reader failed or the text is not UTF-8.
+    Io(std::io::Error),
+    /// The text is not a valid matrix.
+    Parse(ParseError),
+    /// The parsed matrix could not be built.
+    Matrix(MatrixError),
+}
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse(e) => write!(f, "{}", e),
+            Self::Matrix(e) => write!(f, "{}", e),
+        }
+    }
+}
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+            Self::Matrix(e) => Some(e),
+        }
+    }
+}
+impl From<std::io::Error> for ReadError {
+    fn from(e: std::io::Error) -> Self { Self::Io(e) }
+}
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self { Self::Parse(e) }
+}
+impl From<MatrixError> for ReadError {
+    fn from(e: MatrixError) -> Self { Self::Matrix(e) }
+}
+
+/// Layout of the Matrix Market file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarketFormat {
+    /// All elements column by column.
+    Array,
+    /// Non-zero elements as "string row value", indices start from 1.
+    Coordinate,
+}
+
+
+fn tokens<'a>(line: &'a str, separators: &[char], marks: &[char]) -> Vec<(usize, &'a str)> {
+    // (column, token); the marks are separate tokens, columns are counted in characters.
+    let mut out = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    for (column, (i, c)) in line.char_indices().enumerate() {
+        let mark = marks.contains(&c);
+        if c.is_whitespace() || separators.contains(&c) || mark {
+            if let Some((b, col)) = start.take() { out.push((col + 1, &line[b..i])) }
+            if mark { out.push((column + 1, &line[i..i + c.len_utf8()])) }
+        }
+        else if start.is_none() {
+            start = Some((i, column))
+        }
+    }
+    if let Some((b, col)) = start { out.push((col + 1, &line[b..])) }
+    out
+}
+
+fn parse_im(token: &str) -> Option<Im> {
+    // a, bi, a+bi, a-bi, i, -i
+    let Some(im) = token.strip_suffix('i') else {
+        return token.parse::<f64>().ok().map(|e| e.r())
+    };
+
+    let split = im
+        .char_indices()
+        .rev()
+        .find(|(i, c)| *i > 0 && (*c == '+' || *c == '-') && !im[..*i].ends_with(['e', 'E']))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let (real, im) = im.split_at(split);
+
+    let im = match im {
+        "" | "+" => 1.0,
+        "-" => -1.0,
+        _ => im.parse::<f64>().ok()?,
+    };
+    if real.is_empty() { return Some(im.i()) }
+
+    Some(real.parse::<f64>().ok()?.r() + im.i())
+}
+
+fn parse_text<T: Scalar>(s: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Matrix<T>, ParseError> {
+    // [a b c; d e f], the brackets are optional, strings are separated by ';' or new lines.
+    let mut strings = Vec::<(usize, usize, Vec<T>)>::new();
+    let mut string: Option<(usize, usize, Vec<T>)> = None;
+    let mut opened = false;
+    let mut closed = false;
+    let mut started = false;
+
+    for (l, line) in s.lines().enumerate() {
+        let l = l + 1;
+        for (c, token) in tokens(line, &[','], &['[', ']', ';']) {
+            if closed {
+                return Err(ParseError::new(l, c, format!("Unexpected '{}' after ']'.", token)))
+            }
+            match token {
+                "[" if !started => opened = true,
+                "[" => return Err(ParseError::new(l, c, "Unexpected '['.")),
+                "]" if opened => {
+                    closed = true;
+                    strings.extend(string.take());
+                },
+                "]" => return Err(ParseError::new(l, c, "Unexpected ']'.")),
+                ";" => strings.extend(string.take()),
+                _ => {
+                    let e = parse(token)
+                        .ok_or_else(|| ParseError::new(l, c, format!("Invalid number '{}'.", token)))?;
+                    string.get_or_insert((l, c, Vec::new())).2.push(e);
+                },
+            }
+            started = true;
+        }
+        strings.extend(string.take());
+    }
+
+    if opened && !closed {
+        return Err(ParseError::new(s.lines().count().max(1), 1, "']' is missing."))
+    }
+    let Some((_, _, first)) = strings.first() else {
+        return Err(ParseError::new(1, 1, "The matrix is empty."))
+    };
+    let rows = first.len();
+    if let Some((l, c, str)) = strings.iter().find(|(_, _, str)| str.len() != rows) {
+        return Err(ParseError::new(*l, *c, format!("The string has {} elements instead of {}.", str.len(), rows)))
+    }
+
+    let n = strings.len();
+    Ok(Matrix::new(n, rows, strings.into_iter().flat_map(|(_, _, str)| str).collect()).unwrap())
+}
+
+
+impl FromStr for Matrix {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        //! Parses the matrix from the text like "[1 2 3; 4 5 6]".
+        //! Elements are separated by spaces or commas, strings by ';' or new lines,
+        //! the brackets are optional.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m: Matrix = "[1 2; 3 4.5]".parse().unwrap();
+        //!
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 2, rows: 2, body: [1.0, 2.0, 3.0, 4.5] }
+        //!
+        //! let e = "[1 2; 3 x]".parse::<Matrix>().unwrap_err();
+        //!
+        //! println!("{}", e);
+        //! // Invalid number 'x'. (line 1, column 9)
+        //! ```
+
+        parse_text(s, |t| t.parse::<f64>().ok())
+    }
+}
+
+impl FromStr for ImMatrix {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        //! Parses the matrix from the text like "[1+2i -i; 3 4i]", the same way as the [Matrix].
+        //! Elements are written without spaces: a, bi, a+bi.
+        //! # Example
+        //! ```
+        //! use xmath::im::im_matrices::ImMatrix;
+        //! use xmath::im::cast::ImValue;
+        //!
+        //! let m: ImMatrix = "[1+2i -i; 3 4i]".parse().unwrap();
+        //!
+        //! assert_eq!(m, ImMatrix::new(2, 2, vec![
+        //!     1.r() + 2.i(), (-1).i(),
+        //!     3.r(), 4.i(),
+        //! ]).unwrap());
+        //! ```
+
+        parse_text(s, parse_im)
+    }
+}
+
+
+fn read_to_string(mut reader: impl Read) -> std::io::Result<String> {
+    let mut s = String::new();
+    reader.read_to_string(&mut s)?;
+    Ok(s)
+}
+
+fn parse_csv(s: &str) -> Result<Matrix, ParseError> {
+    let mut body = Vec::<f64>::new();
+    let mut rows: Option<usize> = None;
+    let mut strings = 0;
+
+    for (l, line) in s.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let mut column = 1;
+        let mut n = 0;
+        for field in line.split(',') {
+            let value = field.trim();
+            let c = column + field.chars().take_while(|c| c.is_whitespace()).count();
+            if value.is_empty() { return Err(ParseError::new(l + 1, c, "The value is missing.")) }
+
+            body.push(value.parse::<f64>()
+                .map_err(|_| ParseError::new(l + 1, c, format!("Invalid number '{}'.", value)))?);
+            column += field.chars().count() + 1;
+            n += 1;
+        }
+        if *rows.get_or_insert(n) != n {
+            return Err(ParseError::new(l + 1, 1, format!("The string has {} values instead of {}.", n, rows.unwrap())))
+        }
+        strings += 1;
+    }
+
+    let rows = rows.ok_or_else(|| ParseError::new(1, 1, "The matrix is empty."))?;
+    Ok(Matrix::new(strings, rows, body).unwrap())
+}
+
+
+struct Market {
+    strings: usize,
+    rows: usize,
+    // (s, r, value), indices start from 0.
+    entries: Vec<(usize, usize, f64)>,
+}
+
+fn parse_market(s: &str) -> Result<Market, ParseError> {
+    // "%%MatrixMarket matrix coordinate|array real|integer|pattern general|symmetric|skew-symmetric".
+
+    let mut lines = s.lines().enumerate().map(|(l, line)| (l + 1, line));
+
+    let (_, header) = lines.next().ok_or_else(|| ParseError::new(1, 1, "The header is missing."))?;
+    let header = tokens(header, &[], &[]);
+    let word = |i: usize| header.get(i).map(|(c, t)| (*c, t.to_lowercase()));
+    let eol = header.last().map(|(c, t)| c + t.chars().count()).unwrap_or(1);
+
+    if !matches!(word(0), Some((_, w)) if w == "%%matrixmarket") {
+        return Err(ParseError::new(1, 1, "The header must start with '%%MatrixMarket'."))
+    }
+    match word(1) {
+        Some((_, w)) if w == "matrix" => {},
+        Some((c, w)) => return Err(ParseError::new(1, c, format!("Unsupported object '{}'.", w))),
+        None => return Err(ParseError::new(1, eol, "The object is missing.")),
+    }
+    let coordinate = match word(2) {
+        Some((_, w)) if w == "coordinate" => true,
+        Some((_, w)) if w == "array" => false,
+        Some((c, w)) => return Err(ParseError::new(1, c, format!("Unsupported format '{}'.", w))),
+        None => return Err(ParseError::new(1, eol, "The format is missing.")),
+    };
+    let pattern = match word(3) {
+        Some((_, w)) if w == "real" || w == "double" || w == "integer" => false,
+        Some((_, w)) if w == "pattern" && coordinate => true,
+        Some((c, w)) => return Err(ParseError::new(1, c, format!("Unsupported field '{}'.", w))),
+        None => return Err(ParseError::new(1, eol, "The field is missing.")),
+    };
+    // -1 - skew-symmetric, 0 - general, 1 - symmetric
+    let symmetry = match word(4) {
+        Some((_, w)) if w == "general" => 0,
+        Some((_, w)) if w == "symmetric" => 1,
+        Some((_, w)) if w == "skew-symmetric" => -1,
+        Some((c, w)) => return Err(ParseError::new(1, c, format!("Unsupported symmetry '{}'.", w))),
+        None => return Err(ParseError::new(1, eol, "The symmetry is missing.")),
+    };
+
+    let mut data = lines.filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('%'));
+    let mut last = 1;
+
+    let number = |l: usize, (c, t): (usize, &str)| -> Result<f64, ParseError> {
+        t.parse::<f64>().map_err(|_| ParseError::new(l, c, format!("Invalid number '{}'.", t)))
+    };
+    let index = |l: usize, (c, t): (usize, &str), max: usize| -> Result<usize, ParseError> {
+        match t.parse::<usize>() {
+            Ok(i) if i >= 1 && i <= max => Ok(i - 1),
+            _ => Err(ParseError::new(l, c, format!("Invalid index '{}', it must be from 1 to {}.", t, max))),
+        }
+    };
+    let fields = |l: usize, line: &str, n: usize| -> Result<Vec<(usize, String)>, ParseError> {
+        let t = tokens(line, &[], &[]);
+        if t.len() != n {
+            let c = t.get(n).map(|(c, _)| *c).unwrap_or(line.chars().count() + 1);
+            return Err(ParseError::new(l, c, format!("Expected {} values, found {}.", n, t.len())))
+        }
+        Ok(t.into_iter().map(|(c, t)| (c, t.to_string())).collect())
+    };
+
+    let (l, line) = data.next().ok_or_else(|| ParseError::new(1, 1, "The size line is missing."))?;
+    let size = fields(l, line, if coordinate { 3 } else { 2 })?;
+    let mut dims = Vec::new();
+    for (c, t) in &size {
+        match t.parse::<usize>() {
+            Ok(d) => dims.push(d),
+            _ => return Err(ParseError::new(l, *c, format!("Invalid size '{}'.", t))),
+        }
+    }
+    let (strings, rows) = (dims[0], dims[1]);
+    if strings == 0 || rows == 0 { return Err(ParseError::new(l, 1, "The matrix is empty.")) }
+    if symmetry != 0 && strings != rows { return Err(ParseError::new(l, 1, "A symmetric matrix must be square.")) }
+
+    let mut entries = Vec::<(usize, usize, f64)>::new();
+    if coordinate {
+        for _ in 0..dims[2] {
+            let (l, line) = data.next()
+                .ok_or_else(|| ParseError::new(last + 1, 1, format!("Expected {} entries.", dims[2])))?;
+            last = l;
+            let f = fields(l, line, if pattern { 2 } else { 3 })?;
+            let s = index(l, (f[0].0, &f[0].1), strings)?;
+            let r = index(l, (f[1].0, &f[1].1), rows)?;
+            let v = if pattern { 1.0 } else { number(l, (f[2].0, &f[2].1))? };
+            entries.push((s, r, v));
+        }
+    }
+    else {
+        // column by column, symmetric matrices store only the lower triangle
+        let positions = (0..rows).flat_map(|r| {
+            let from = match symmetry { 0 => 0, 1 => r, _ => r + 1 };
+            (from..strings).map(move |s| (s, r))
+        });
+        for (s, r) in positions {
+            let (l, line) = data.next()
+                .ok_or_else(|| ParseError::new(last + 1, 1, "Not enough values."))?;
+            last = l;
+            let f = fields(l, line, 1)?;
+            entries.push((s, r, number(l, (f[0].0, &f[0].1))?));
+        }
+    }
+    if let Some((l, _)) = data.next() {
+        return Err(ParseError::new(l, 1, "Too many values."))
+    }
+
+    if symmetry != 0 {
+        let mirrored = entries
+            .iter()
+            .filter(|(s, r, _)| s != r)
+            .map(|(s, r, v)| (*r, *s, *v * symmetry as f64))
+            .collect::<Vec<_>>();
+        entries.extend(mirrored);
+    }
+    Ok(Market { strings, rows, entries })
+}
+
+fn write_market(mut writer: impl Write, format: MarketFormat, strings: usize, rows: usize, nnz: usize,
+                entries: impl Iterator<Item = (usize, usize, f64)>) -> std::io::Result<()>
+{
+    // The entries of the array format must go column by column.
+    match format {
+        MarketFormat::Coordinate => {
+            writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+            writeln!(writer, "{} {} {}", strings, rows, nnz)?;
+            for (s, r, v) in entries {
+                writeln!(writer, "{} {} {}", s + 1, r + 1, v)?;
+            }
+        },
+        MarketFormat::Array => {
+            writeln!(writer, "%%MatrixMarket matrix array real general")?;
+            writeln!(writer, "{} {}", strings, rows)?;
+            for (_, _, v) in entries {
+                writeln!(writer, "{}", v)?;
+            }
+        },
+    }
+    Ok(())
+}
+
+
+impl Matrix {
+    pub fn read_csv(reader: impl Read) -> Result<Self, ReadError> {
+        //! Reads the matrix from CSV, one string per line, values are separated by commas.
+        //! Empty lines are skipped. Errors are [ReadError].
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::read_csv("1, 2\n3, 4\n".as_bytes()).unwrap();
+        //!
+        //! let mut csv = Vec::new();
+        //! m.write_csv(&mut csv).unwrap();
+        //!
+        //! println!("{}", String::from_utf8(csv).unwrap());
+        //! // 1,2
+        //! // 3,4
+        //! ```
+
+        Ok(parse_csv(&read_to_string(reader)?)?)
+    }
+
+    pub fn write_csv(&self, mut writer: impl Write) -> std::io::Result<()> {
+        for str in self.body.chunks(self.rows) {
+            let line = str.iter().map(|e| e.to_string()).collect::<Vec<String>>().join(",");
+            writeln!(writer, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    pub fn read_matrix_market(reader: impl Read) -> Result<Self, ReadError> {
+        //! Reads the matrix in the Matrix Market format (array or coordinate;
+        //! real, integer or pattern; general, symmetric or skew-symmetric).
+        //! Errors are [ReadError].
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::matrices::io::MarketFormat;
+        //!
+        //! let text = "%%MatrixMarket matrix coordinate real symmetric
+        //! % comment
+        //! 2 2 2
+        //! 1 1 4.0
+        //! 2 1 -1.0
+        //! ";
+        //! let m = Matrix::read_matrix_market(text.as_bytes()).unwrap();
+        //!
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 2, rows: 2, body: [4.0, -1.0, -1.0, 0.0] }
+        //!
+        //! let mut out = Vec::new();
+        //! m.write_matrix_market(&mut out, MarketFormat::Array).unwrap();
+        //!
+        //! println!("{}", String::from_utf8(out).unwrap());
+        //! // %%MatrixMarket matrix array real general
+        //! // 2 2
+        //! // 4
+        //! // -1
+        //! // -1
+        //! // 0
+        //! ```
+
+        let market = parse_market(&read_to_string(reader)?)?;
+        let mut body = vec![0.0; market.strings * market.rows];
+        for (s, r, v) in market.entries {
+            body[s * market.rows + r] += v;
+        }
+        Ok(Self::new(market.strings, market.rows, body)?)
+    }
+
+    pub fn write_matrix_market(&self, writer: impl Write, format: MarketFormat) -> std::io::Result<()> {
+        let (strings, rows) = (self.strings, self.rows);
+        match format {
+            MarketFormat::Array => {
+                let columns = (0..rows).flat_map(|r| (0..strings).map(move |s| (s, r, self.body[s * rows + r])));
+                write_market(writer, format, strings, rows, strings * rows, columns)
+            },
+            MarketFormat::Coordinate => {
+                let nnz = self.body.iter().filter(|e| **e != 0.0).count();
+                let elements = (0..strings * rows)
+                    .map(|i| (i / rows, i % rows, self.body[i]))
+                    .filter(|(_, _, v)| *v != 0.0);
+                write_market(writer, format, strings, rows, nnz, elements)
+            },
+        }
+    }
+}
+
+
+impl SparseMatrix {
+    pub fn read_matrix_market(reader: impl Read) -> Result<Self, ReadError> {
+        //! Reads the sparse matrix in the Matrix Market format, see [Matrix::read_matrix_market].
+
+        let market = parse_market(&read_to_string(reader)?)?;
+        let mut b = CooBuilder::new(market.strings, market.rows)?;
+        for (s, r, v) in market.entries {
+            b.push(s, r, v)?;
+        }
+        Ok(b.build())
+    }
+
+    pub fn write_matrix_market(&self, writer: impl Write) -> std::io::Result<()> {
+        //! Writes the sparse matrix in the Matrix Market coordinate format.
+
+        let elements = (0..self.strings()).flat_map(|s| self.string(s).map(move |(r, v)| (s, r, v)));
+        write_market(writer, MarketFormat::Coordinate, self.strings(), self.rows(), self.nnz(), elements)
+    }
+}
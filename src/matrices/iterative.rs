@@ -0,0 +1,376 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::sparse::SparseMatrix;
+use crate::vector_algebra::Vector;
+
+
+pub trait LinearOperator {
+    //! Anything that can be multiplied by a vector can be solved by [cg] and [gmres].
+
+    fn strings(&self) -> usize;
+    fn rows(&self) -> usize;
+    fn apply(&self, x: &[f64]) -> Vec<f64>;
+}
+
+pub trait StringAccess: LinearOperator {
+    //! [jacobi], [gauss_seidel] and [sor] also need the elements of the strings.
+
+    fn string_elements(&self, s: usize) -> Vec<(usize, f64)>;
+}
+
+impl LinearOperator for Matrix {
+    fn strings(&self) -> usize {
+        self.strings
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.body
+            .chunks(self.rows)
+            .map(|str| str.iter().zip(x).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+}
+impl StringAccess for Matrix {
+    fn string_elements(&self, s: usize) -> Vec<(usize, f64)> {
+        self.body[s * self.rows..(s + 1) * self.rows]
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| **e != 0.0)
+            .map(|(r, e)| (r, *e))
+            .collect()
+    }
+}
+
+impl LinearOperator for SparseMatrix {
+    fn strings(&self) -> usize {
+        SparseMatrix::strings(self)
+    }
+
+    fn rows(&self) -> usize {
+        SparseMatrix::rows(self)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.mul_vec(x).unwrap().into_vec()
+    }
+}
+impl StringAccess for SparseMatrix {
+    fn string_elements(&self, s: usize) -> Vec<(usize, f64)> {
+        self.string(s).collect()
+    }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct IterativeSettings {
+    /// The solver stops when |A*x - d| <= tolerance * |d|.
+    pub tolerance: f64,
+    pub max_iter: usize,
+    /// The initial guess, zeros by default.
+    pub x0: Option<Vec<f64>>,
+}
+impl Default for IterativeSettings {
+    fn default() -> Self {
+        Self { tolerance: 1e-10, max_iter: 1000, x0: None }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Convergence {
+    pub converged: bool,
+    pub iterations: usize,
+    /// Relative residuals |A*x - d| / |d|, starting with the initial guess.
+    pub residuals: Vec<f64>,
+}
+
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn residual(op: &impl LinearOperator, x: &[f64], d: &[f64]) -> Vec<f64> {
+    op.apply(x).iter().zip(d).map(|(ax, d)| d - ax).collect()
+}
+
+fn prepare(op: &impl LinearOperator, d: &[f64], settings: &IterativeSettings) -> Result<(Vec<f64>, f64), MatrixError> {
+    // Returns the initial guess and the norm which the residuals are divided by.
+    let shape = (op.strings(), op.rows());
+    if shape.0 != shape.1 { return Err(MatrixError::NotSquare { strings: shape.0, rows: shape.1 }) }
+    if d.len() != shape.0 { return Err(MatrixError::DimensionMismatch { left: shape, right: (d.len(), 1) }) }
+
+    let x = match &settings.x0 {
+        Some(x0) if x0.len() != op.rows() => return Err(MatrixError::DimensionMismatch { left: shape, right: (x0.len(), 1) }),
+        Some(x0) => x0.to_vec(),
+        None => vec![0.0; op.rows()],
+    };
+
+    let d_norm = norm(d);
+    Ok((x, if d_norm == 0.0 { 1.0 } else { d_norm }))
+}
+
+fn diagonal(op: &impl StringAccess) -> Result<Vec<f64>, MatrixError> {
+    (0..op.strings())
+        .map(|s| match op.string_elements(s).iter().find(|(r, _)| *r == s) {
+            Some((_, e)) if *e != 0.0 => Ok(*e),
+            _ => Err(MatrixError::InvalidArgument(format!("The diagonal element {} is zero.", s+1))),
+        })
+        .collect()
+}
+
+
+pub fn jacobi(op: &impl StringAccess, d: &[f64], settings: &IterativeSettings) -> Result<(Vector, Convergence), MatrixError> {
+    //! Jacobi method, converges for diagonally dominant matrices.
+    //! # Example
+    //! ```
+    //! use xmath::matrices::Matrix;
+    //! use xmath::matrices::iterative::{jacobi, IterativeSettings};
+    //!
+    //! let m = Matrix::new(3, 3, vec![
+    //!     4.0, -1.0, 0.0,
+    //!     -1.0, 4.0, -1.0,
+    //!     0.0, -1.0, 4.0,
+    //! ]).unwrap();
+    //!
+    //! let (x, report) = jacobi(&m, &[2.0, 4.0, 10.0], &IterativeSettings::default()).unwrap();
+    //!
+    //! println!("x = {:.4}, converged = {}", x, report.converged);
+    //! // x = Vector (3) = [1.0000, 2.0000, 3.0000], converged = true
+    //! ```
+
+    let (mut x, d_norm) = prepare(op, d, settings)?;
+    let diag = diagonal(op)?;
+    let mut report = Convergence::default();
+    report.residuals.push(norm(&residual(op, &x, d)) / d_norm);
+
+    while report.residuals[report.iterations] > settings.tolerance && report.iterations < settings.max_iter {
+        x = (0..op.strings())
+            .map(|s| {
+                let sum = op.string_elements(s)
+                    .iter()
+                    .filter(|(r, _)| *r != s)
+                    .map(|(r, e)| e * x[*r])
+                    .sum::<f64>();
+                (d[s] - sum) / diag[s]
+            })
+            .collect();
+
+        report.iterations += 1;
+        report.residuals.push(norm(&residual(op, &x, d)) / d_norm);
+    }
+
+    report.converged = report.residuals[report.iterations] <= settings.tolerance;
+    Ok((Vector::from(x), report))
+}
+
+pub fn gauss_seidel(op: &impl StringAccess, d: &[f64], settings: &IterativeSettings) -> Result<(Vector, Convergence), MatrixError> {
+    //! Gauss-Seidel method, converges for diagonally dominant and symmetric positive definite matrices.
+    //! # Example
+    //! ```
+    //! use xmath::matrices::Matrix;
+    //! use xmath::matrices::iterative::{gauss_seidel, IterativeSettings};
+    //!
+    //! let m = Matrix::new(3, 3, vec![
+    //!     4.0, -1.0, 0.0,
+    //!     -1.0, 4.0, -1.0,
+    //!     0.0, -1.0, 4.0,
+    //! ]).unwrap();
+    //!
+    //! let (x, report) = gauss_seidel(&m, &[2.0, 4.0, 10.0], &IterativeSettings::default()).unwrap();
+    //!
+    //! println!("x = {:.4}, converged = {}", x, report.converged);
+    //! // x = Vector (3) = [1.0000, 2.0000, 3.0000], converged = true
+    //! ```
+
+    sor(op, d, 1.0, settings)
+}
+
+pub fn sor(op: &impl StringAccess, d: &[f64], omega: f64, settings: &IterativeSettings) -> Result<(Vector, Convergence), MatrixError> {
+    //! Successive over-relaxation with the relaxation factor 0 < omega < 2
+    //! (omega = 1 is the Gauss-Seidel method).
+    //! # Example
+    //! ```
+    //! use xmath::matrices::Matrix;
+    //! use xmath::matrices::iterative::{sor, IterativeSettings};
+    //!
+    //! let m = Matrix::new(3, 3, vec![
+    //!     4.0, -1.0, 0.0,
+    //!     -1.0, 4.0, -1.0,
+    //!     0.0, -1.0, 4.0,
+    //! ]).unwrap();
+    //!
+    //! let (x, report) = sor(&m, &[2.0, 4.0, 10.0], 1.1, &IterativeSettings::default()).unwrap();
+    //!
+    //! println!("x = {:.4}, converged = {}", x, report.converged);
+    //! // x = Vector (3) = [1.0000, 2.0000, 3.0000], converged = true
+    //! ```
+
+    if omega <= 0.0 || omega >= 2.0 { return Err(MatrixError::InvalidArgument("The relaxation factor must be in (0, 2).".to_string())) }
+
+    let (mut x, d_norm) = prepare(op, d, settings)?;
+    let diag = diagonal(op)?;
+    let mut report = Convergence::default();
+    report.residuals.push(norm(&residual(op, &x, d)) / d_norm);
+
+    while report.residuals[report.iterations] > settings.tolerance && report.iterations < settings.max_iter {
+        for s in 0..op.strings() {
+            let sum = op.string_elements(s)
+                .iter()
+                .filter(|(r, _)| *r != s)
+                .map(|(r, e)| e * x[*r])
+                .sum::<f64>();
+            x[s] = (1.0 - omega) * x[s] + omega * (d[s] - sum) / diag[s];
+        }
+
+        report.iterations += 1;
+        report.residuals.push(norm(&residual(op, &x, d)) / d_norm);
+    }
+
+    report.converged = report.residuals[report.iterations] <= settings.tolerance;
+    Ok((Vector::from(x), report))
+}
+
+pub fn cg(op: &impl LinearOperator, d: &[f64], settings: &IterativeSettings) -> Result<(Vector, Convergence), MatrixError> {
+    //! Conjugate gradient method for symmetric positive definite matrices.
+    //! # Example
+    //! ```
+    //! use xmath::matrices::Matrix;
+    //! use xmath::matrices::iterative::{cg, IterativeSettings};
+    //!
+    //! let m = Matrix::new(3, 3, vec![
+    //!     4.0, -1.0, 0.0,
+    //!     -1.0, 4.0, -1.0,
+    //!     0.0, -1.0, 4.0,
+    //! ]).unwrap();
+    //!
+    //! let (x, report) = cg(&m, &[2.0, 4.0, 10.0], &IterativeSettings::default()).unwrap();
+    //!
+    //! println!("x = {:.4}, iterations = {}", x, report.iterations);
+    //! // x = Vector (3) = [1.0000, 2.0000, 3.0000], iterations = 3
+    //! ```
+
+    let (mut x, d_norm) = prepare(op, d, settings)?;
+    let mut r = residual(op, &x, d);
+    let mut p = r.clone();
+    let mut rr = dot(&r, &r);
+    let mut report = Convergence::default();
+    report.residuals.push(rr.sqrt() / d_norm);
+
+    while report.residuals[report.iterations] > settings.tolerance && report.iterations < settings.max_iter {
+        let ap = op.apply(&p);
+        let pap = dot(&p, &ap);
+        if pap <= 0.0 { return Err(MatrixError::NegativeCurvature { iteration: report.iterations + 1 }) }
+
+        let alpha = rr / pap;
+        x.iter_mut().zip(&p).for_each(|(e, p)| *e += alpha * p);
+        r.iter_mut().zip(&ap).for_each(|(e, ap)| *e -= alpha * ap);
+
+        let rr_new = dot(&r, &r);
+        let beta = rr_new / rr;
+        p.iter_mut().zip(&r).for_each(|(e, r)| *e = r + beta * *e);
+        rr = rr_new;
+
+        report.iterations += 1;
+        report.residuals.push(rr.sqrt() / d_norm);
+    }
+
+    report.converged = report.residuals[report.iterations] <= settings.tolerance;
+    Ok((Vector::from(x), report))
+}
+
+pub fn gmres(op: &impl LinearOperator, d: &[f64], restart: usize, settings: &IterativeSettings) -> Result<(Vector, Convergence), MatrixError> {
+    //! Restarted GMRES (Generalized Minimal RESidual) method for any nonsingular matrix.
+    //! The Krylov basis is rebuilt after every 'restart' iterations.
+    //! # Example
+    //! ```
+    //! use xmath::matrices::Matrix;
+    //! use xmath::matrices::iterative::{gmres, IterativeSettings};
+    //!
+    //! let m = Matrix::new(3, 3, vec![
+    //!     1.0, 4.0, 2.0,
+    //!     2.0, -6.0, -2.0,
+    //!     1.0, 5.0, 2.0,
+    //! ]).unwrap();
+    //!
+    //! let (x, report) = gmres(&m, &[1.0, 3.0, 2.0], 10, &IterativeSettings::default()).unwrap();
+    //!
+    //! println!("x = {:.4}, converged = {}", x, report.converged);
+    //! // x = Vector (3) = [2.0000, 1.0000, -2.5000], converged = true
+    //! ```
+
+    if restart == 0 { return Err(MatrixError::InvalidArgument("The restart must be greater than 0.".to_string())) }
+
+    let (mut x, d_norm) = prepare(op, d, settings)?;
+    let mut r = residual(op, &x, d);
+    let mut beta = norm(&r);
+    let mut report = Convergence::default();
+    report.residuals.push(beta / d_norm);
+
+    while report.residuals[report.iterations] > settings.tolerance && report.iterations < settings.max_iter {
+        // Arnoldi process, H is reduced to the upper triangular form by Givens rotations.
+        let mut v = vec![r.iter().map(|e| e / beta).collect::<Vec<f64>>()];
+        let mut h = vec![vec![0.0; restart]; restart + 1];
+        let (mut cs, mut sn) = (vec![0.0; restart], vec![0.0; restart]);
+        let mut g = vec![0.0; restart + 1];
+        g[0] = beta;
+
+        let mut k = 0;
+        while k < restart && report.iterations < settings.max_iter {
+            let mut w = op.apply(&v[k]);
+            for (i, vi) in v.iter().enumerate() {
+                h[i][k] = dot(&w, vi);
+                w.iter_mut().zip(vi).for_each(|(e, vi)| *e -= h[i][k] * vi);
+            }
+            let w_norm = norm(&w);
+            h[k + 1][k] = w_norm;
+
+            for i in 0..k {
+                let t = cs[i] * h[i][k] + sn[i] * h[i + 1][k];
+                h[i + 1][k] = cs[i] * h[i + 1][k] - sn[i] * h[i][k];
+                h[i][k] = t;
+            }
+            let denom = h[k][k].hypot(h[k + 1][k]);
+            if denom == 0.0 { break }
+            cs[k] = h[k][k] / denom;
+            sn[k] = h[k + 1][k] / denom;
+            h[k][k] = denom;
+            h[k + 1][k] = 0.0;
+            g[k + 1] = -sn[k] * g[k];
+            g[k] *= cs[k];
+
+            k += 1;
+            report.iterations += 1;
+            report.residuals.push(g[k].abs() / d_norm);
+
+            if g[k].abs() / d_norm <= settings.tolerance || w_norm == 0.0 { break }
+            v.push(w.iter().map(|e| e / w_norm).collect());
+        }
+        if k == 0 { break }
+
+        // H*y = g
+        let mut y = g[..k].to_vec();
+        for s in (0..k).rev() {
+            for c in s+1..k {
+                y[s] -= h[s][c] * y[c];
+            }
+            y[s] /= h[s][s];
+        }
+        for (yi, vi) in y.iter().zip(&v) {
+            x.iter_mut().zip(vi).for_each(|(e, vi)| *e += yi * vi);
+        }
+
+        r = residual(op, &x, d);
+        beta = norm(&r);
+        report.residuals[report.iterations] = beta / d_norm;
+    }
+
+    report.converged = report.residuals[report.iterations] <= settings.tolerance;
+    Ok((Vector::from(x), report))
+}
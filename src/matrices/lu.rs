@@ -0,0 +1,264 @@
+use crate::im::core::Im;
+use crate::matrices::{noise_level, Matrix};
+use crate::matrices::eigen::{c_abs, c_div, c_mul, from_im, Complex};
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+
+
+pub(crate) struct PackedLU {
+    size: usize,
+    body: Vec<f64>,
+    perm: Vec<usize>,
+    sign: f64,
+    pub(crate) is_singular: bool,
+}
+impl PackedLU {
+    pub(crate) fn det(&self) -> f64 {
+        // The product of the pivots, is_singular only gates the solvers.
+        (0..self.size)
+            .map(|i| self.body[i * self.size + i])
+            .product::<f64>() * self.sign
+    }
+
+    pub(crate) fn solve(&self, d: &[f64]) -> Vec<f64> {
+        let n = self.size;
+        let mut x: Vec<f64> = self.perm
+            .iter()
+            .map(|p| d[*p])
+            .collect();
+
+        // L*y = P*d
+        for s in 0..n {
+            for r in 0..s {
+                x[s] -= self.body[s * n + r] * x[r];
+            }
+        }
+
+        // U*x = y
+        for s in (0..n).rev() {
+            for r in s+1..n {
+                x[s] -= self.body[s * n + r] * x[r];
+            }
+            x[s] /= self.body[s * n + s];
+        }
+        x
+    }
+
+    pub(crate) fn solve_t(&self, d: &[f64]) -> Vec<f64> {
+        // A^T = U^T * L^T * P
+        let n = self.size;
+        let mut y = d.to_vec();
+
+        // U^T*w = d
+        for s in 0..n {
+            for r in 0..s {
+                y[s] -= self.body[r * n + s] * y[r];
+            }
+            y[s] /= self.body[s * n + s];
+        }
+
+        // L^T*v = w
+        for s in (0..n).rev() {
+            for r in s+1..n {
+                y[s] -= self.body[r * n + s] * y[r];
+            }
+        }
+
+        // P*x = v
+        let mut x = vec![0.0; n];
+        for (i, p) in self.perm.iter().enumerate() {
+            x[*p] = y[i]
+        }
+        x
+    }
+}
+
+
+pub(crate) struct ComplexLU {
+    size: usize,
+    body: Vec<Complex>,
+    perm: Vec<usize>,
+    sign: f64,
+    pub(crate) is_singular: bool,
+}
+impl ComplexLU {
+    pub(crate) fn det(&self) -> Complex {
+        (0..self.size)
+            .map(|i| self.body[i * self.size + i])
+            .fold((self.sign, 0.0), c_mul)
+    }
+
+    pub(crate) fn solve(&self, d: &[Complex]) -> Vec<Complex> {
+        let n = self.size;
+        let mut x: Vec<Complex> = self.perm
+            .iter()
+            .map(|p| d[*p])
+            .collect();
+
+        // L*y = P*d
+        for s in 0..n {
+            for r in 0..s {
+                let e = c_mul(self.body[s * n + r], x[r]);
+                x[s] = (x[s].0 - e.0, x[s].1 - e.1);
+            }
+        }
+
+        // U*x = y
+        for s in (0..n).rev() {
+            for r in s+1..n {
+                let e = c_mul(self.body[s * n + r], x[r]);
+                x[s] = (x[s].0 - e.0, x[s].1 - e.1);
+            }
+            x[s] = c_div(x[s], self.body[s * n + s]);
+        }
+        x
+    }
+}
+
+
+impl<T: Real> Matrix<T> {
+    pub fn lu(&self) -> Result<(Self, Self, Vec<usize>), MatrixError> {
+        //! LU decomposition with partial pivoting: P*A = L*U.
+        //!
+        //! L - lower triangular matrix with ones on the diagonal
+        //!
+        //! U - upper triangular matrix
+        //!
+        //! p - permutation, the i-th string of P*A is the p\[i\]-th string of A
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 4.0, 2.0,
+        //!     2.0, -6.0, -2.0,
+        //!     1.0, 5.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! let (l, u, p) = m.lu().unwrap();
+        //!
+        //! println!("p = {:?}", p);
+        //! // p = [1, 2, 0]
+        //! println!("L*U = {:?}", l * u);
+        //! // L*U = Matrix { strings: 3, rows: 3, body: [2.0, -6.0, -2.0, 1.0, 5.0, 2.0, 1.0, 4.0, 2.0] }
+        //! ```
+
+        let (l, u, p) = self._f64()._lu_factors()?;
+        Ok((T::matrix_from_f64(l), T::matrix_from_f64(u), p))
+    }
+}
+
+
+impl Matrix {
+    fn _lu_factors(&self) -> Result<(Self, Self, Vec<usize>), MatrixError> {
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+
+        let n = self.rows;
+        let packed = self._lu();
+        let mut l = vec![0.0; n * n];
+        let mut u = vec![0.0; n * n];
+
+        for s in 0..n {
+            for r in 0..n {
+                let i = s * n + r;
+                if r < s { l[i] = packed.body[i] }
+                else {
+                    u[i] = packed.body[i];
+                    if r == s { l[i] = 1.0 }
+                }
+            }
+        }
+
+        Ok((Self::new(n, n, l)?, Self::new(n, n, u)?, packed.perm))
+    }
+
+    pub(crate) fn _lu(&self) -> PackedLU {
+        // L (without its unit diagonal) and U share one body.
+        let n = self.rows;
+        let mut body = self.body.to_vec();
+        let mut perm = (0..n).collect::<Vec<usize>>();
+        let mut sign = 1.0;
+
+        // Pivots smaller than this are rounding noise of a singular matrix (for inverse and slae).
+        let tolerance = noise_level(n, &self.body);
+        let mut is_singular = false;
+
+        for k in 0..n {
+            let mut p = k;
+            for s in k+1..n {
+                if body[s * n + k].abs() > body[p * n + k].abs() { p = s }
+            }
+            if p != k {
+                for r in 0..n {
+                    body.swap(k * n + r, p * n + r);
+                }
+                perm.swap(k, p);
+                sign = -sign;
+            }
+
+            let pivot = body[k * n + k];
+            if pivot.abs() <= tolerance { is_singular = true }
+            if pivot == 0.0 { continue }
+
+            for s in k+1..n {
+                let f = body[s * n + k] / pivot;
+                body[s * n + k] = f;
+                if f == 0.0 { continue }
+                for r in k+1..n {
+                    body[s * n + r] -= f * body[k * n + r];
+                }
+            }
+        }
+
+        PackedLU { size: n, body, perm, sign, is_singular }
+    }
+}
+
+
+impl Matrix<Im> {
+    pub(crate) fn _complex_lu(&self) -> Option<ComplexLU> {
+        // None if some element is symbolic, such matrices are left to the cofactor expansion.
+        let n = self.rows;
+        let mut body = self.body
+            .iter()
+            .map(from_im)
+            .collect::<Option<Vec<Complex>>>()?;
+        let mut perm = (0..n).collect::<Vec<usize>>();
+        let mut sign = 1.0;
+
+        let tolerance = noise_level(n, &body.iter().map(|e| c_abs(*e)).collect::<Vec<f64>>());
+        let mut is_singular = false;
+
+        for k in 0..n {
+            let mut p = k;
+            for s in k+1..n {
+                if c_abs(body[s * n + k]) > c_abs(body[p * n + k]) { p = s }
+            }
+            if p != k {
+                for r in 0..n {
+                    body.swap(k * n + r, p * n + r);
+                }
+                perm.swap(k, p);
+                sign = -sign;
+            }
+
+            let pivot = body[k * n + k];
+            if c_abs(pivot) <= tolerance { is_singular = true }
+            if c_abs(pivot) == 0.0 { continue }
+
+            for s in k+1..n {
+                let f = c_div(body[s * n + k], pivot);
+                body[s * n + k] = f;
+                if c_abs(f) == 0.0 { continue }
+                for r in k+1..n {
+                    let e = c_mul(f, body[k * n + r]);
+                    body[s * n + r] = (body[s * n + r].0 - e.0, body[s * n + r].1 - e.1);
+                }
+            }
+        }
+
+        Some(ComplexLU { size: n, body, perm, sign, is_singular })
+    }
+}
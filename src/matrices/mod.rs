@@ -0,0 +1,663 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::{Real, Scalar};
+use crate::utils::default::PRECISION;
+use crate::vector_algebra::Vector;
+
+const MUL_BLOCK: usize = 64;
+// Smaller products (multiplications count) are not worth spawning threads.
+const MUL_PARALLEL_THRESHOLD: usize = 1 << 18;
+
+pub mod scalar;
+pub mod smatrix;
+pub mod sparse;
+pub mod iterative;
+pub mod view;
+pub mod solution;
+pub mod error;
+pub mod io;
+pub(crate) mod lu;
+pub(crate) mod qr;
+pub(crate) mod eigen;
+pub(crate) mod svd;
+pub(crate) mod cholesky;
+pub(crate) mod functions;
+pub(crate) mod norms;
+pub(crate) mod rref;
+pub(crate) mod blocks;
+pub(crate) mod constructors;
+pub(crate) mod ops;
+pub(crate) mod export;
+
+
+pub(crate) fn noise_level<'a>(n: usize, elements: impl IntoIterator<Item = &'a f64>) -> f64 {
+    // Values below this are rounding noise of n-term sums of the elements.
+    let max = elements.into_iter().fold(0.0_f64, |m, e| m.max(e.abs()));
+    n as f64 * f64::EPSILON * max
+}
+
+
+/// Matrix of f64 (f32, [Im](crate::im::core::Im)) elements, stored by strings.
+/// The decompositions, norms and matrix functions need [Real] elements (not Im).
+/// With the `serde` feature it is (de)serialized as {"strings": 2, "rows": 2, "body": [1.0, 2.0, 3.0, 4.0]},
+/// the shape is checked on deserialization.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MatrixData<T>", bound(deserialize = "T: Scalar + serde::Deserialize<'de>")))]
+pub struct Matrix<T = f64> {
+    strings: usize,
+    rows: usize,
+    pub(crate) body: Vec<T>,
+}
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixData<T> {
+    strings: usize,
+    rows: usize,
+    body: Vec<T>,
+}
+#[cfg(feature = "serde")]
+impl<T: Scalar> TryFrom<MatrixData<T>> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(m: MatrixData<T>) -> Result<Self, Self::Error> {
+        Self::new(m.strings, m.rows, m.body)
+    }
+}
+impl<T: Scalar> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pretty(f.precision(), f.width().unwrap_or(0)))
+    }
+}
+impl<T: Scalar> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.strings == other.strings &&
+            self.rows == other.rows &&
+            self.body
+                .iter()
+                .enumerate()
+                .all(|(i, n)| n.approx_eq(&other.body[i], PRECISION))
+    }
+}
+impl<T: Scalar> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (s, r): (usize, usize)) -> &Self::Output {
+        self.get(s, r).expect("The index is out of the matrix.")
+    }
+}
+impl<T: Scalar> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (s, r): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(s, r).expect("The index is out of the matrix.")
+    }
+}
+impl<T: Scalar> Matrix<T> {
+    pub fn new(strings: usize, rows: usize, body: Vec<T>) -> Result<Self, MatrixError> {
+        if strings == 0 || rows == 0 { return Err(MatrixError::Empty) }
+        if body.len() != strings * rows {
+            return Err(MatrixError::InvalidArgument(format!("The body has {} elements instead of {}.", body.len(), strings * rows)))
+        }
+
+        Ok(Self { strings, rows, body })
+    }
+
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get(&self, s: usize, r: usize) -> Option<&T> {
+        //! The element of the s-th string and the r-th row, None if the index is out of the matrix.
+        //! Also available as m\[(s, r)\].
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let mut m = Matrix::new(2, 2, vec![
+        //!     1.0, 2.0,
+        //!     3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! m[(0, 1)] = 5.0;
+        //! m.set(1, 0, 6.0).unwrap();
+        //!
+        //! println!("{:?} {:?} {}", m.get(0, 1), m.get(2, 0), m[(1, 0)]);
+        //! // Some(5.0) None 6
+        //! ```
+
+        if s >= self.strings || r >= self.rows { return None }
+
+        self.body.get(s * self.rows + r)
+    }
+
+    pub fn get_mut(&mut self, s: usize, r: usize) -> Option<&mut T> {
+        if s >= self.strings || r >= self.rows { return None }
+
+        self.body.get_mut(s * self.rows + r)
+    }
+
+    pub fn set(&mut self, s: usize, r: usize, value: T) -> Result<(), MatrixError> {
+        let shape = (self.strings, self.rows);
+        *self.get_mut(s, r).ok_or(MatrixError::IndexOutOfBounds { index: (s, r), shape })? = value;
+        Ok(())
+    }
+
+    pub fn row(&self, s: usize) -> &[T] {
+        //! The s-th string of the matrix.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //! ]).unwrap();
+        //!
+        //! println!("row = {:?}, col = {:?}", m.row(1), m.col(2));
+        //! // row = [4.0, 5.0, 6.0], col = [3.0, 6.0]
+        //! ```
+
+        &self.body[s * self.rows..(s + 1) * self.rows]
+    }
+
+    pub fn row_mut(&mut self, s: usize) -> &mut [T] {
+        &mut self.body[s * self.rows..(s + 1) * self.rows]
+    }
+
+    pub fn col(&self, r: usize) -> Vec<T> {
+        //! The r-th row (column) of the matrix, copied since it's not contiguous in the body.
+
+        assert!(r < self.rows, "The index is out of the matrix.");
+        self.body
+            .iter()
+            .skip(r)
+            .step_by(self.rows)
+            .cloned()
+            .collect()
+    }
+
+    pub fn det(&self) -> T {
+        //! The matrix determinant (LU decomposition for real and complex numbers, cofactor expansion for symbolic Im).
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(5, 5, vec![
+        //!     0.0, 5.0, 6.0, 7.0, 1.0,
+        //!     1.0, 4.0, 5.0, 1.0, 1.0,
+        //!     0.0, 3.0, 1.0, 2.0, 2.0,
+        //!     0.0, 1.0, 7.0, 8.0, 6.0,
+        //!     0.0, 1.0, 4.0, 4.0, 7.0,
+        //! ]).unwrap();
+        //!
+        //! println!("det = {:.1}", m.det());
+        //! // det = 156.0
+        //! ```
+
+        if self.rows != self.strings { return T::zero() }
+
+        T::matrix_det(self)
+    }
+
+    pub(crate) fn _sub_matrix(&self, s: usize, r: usize) -> Self {
+        let str = (s * self.rows..s * self.rows + self.rows).collect::<Vec<usize>>();
+
+        let sub_matrix: Vec<T> = self.body
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)|
+                if (i % self.rows != r) && !str.iter().any(|n| *n == i) {Some(e.clone())} else {None}
+            )
+            .collect();
+
+        let size = sub_matrix.len().isqrt();
+
+        Self::new(
+            size,
+            size,
+            sub_matrix
+        ).unwrap()
+    }
+
+    pub fn mul_by_ref(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Matrix multiplication by references.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(3, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //!     1.0, 2.0, 3.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(3, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     1.0, 2.0, 3.0,
+        //!     5.0, 2.0, 1.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m1 * m2 = {}", m1.mul_by_ref(&m2).unwrap());
+        //! println!("m1 * m2 = {}", m1 * m2);
+        //! // m1 * m2 = Matrix (3x3) = [
+        //! //  18 12 12
+        //! //  39 30 33
+        //! //  18 12 12
+        //! // ]
+        //! // m1 * m2 = Matrix (3x3) = [
+        //! //  18 12 12
+        //! //  39 30 33
+        //! //  18 12 12
+        //! // ]
+        //! ```
+
+        if self.rows != rhs.strings {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (rhs.strings, rhs.rows) })
+        }
+        let (left, right) = (self, rhs);
+
+        let (strings, rows) = (left.strings, right.rows);
+        let mut new_m = vec![T::zero(); strings * rows];
+
+        if strings * left.rows * rows < MUL_PARALLEL_THRESHOLD {
+            left._mul_block(right, 0, &mut new_m);
+        } else {
+            let max_threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+            let one_thread_strings = strings.div_ceil(max_threads);
+
+            std::thread::scope(|s| {
+                for (th, chunk) in new_m.chunks_mut(one_thread_strings * rows).enumerate() {
+                    s.spawn(move || left._mul_block(right, th * one_thread_strings, chunk));
+                }
+            });
+        }
+        Self::new(strings, rows, new_m)
+    }
+
+    fn _mul_block(&self, rhs: &Self, first_string: usize, out: &mut [T]) {
+        // out += self[first_string..] * rhs, blocked so that the rhs block stays in the cache.
+        let (n, m) = (self.rows, rhs.rows);
+
+        for kb in (0..n).step_by(MUL_BLOCK) {
+            for rb in (0..m).step_by(MUL_BLOCK) {
+                let r_end = (rb + MUL_BLOCK).min(m);
+
+                for (s, out_str) in (first_string..).zip(out.chunks_mut(m)) {
+                    for k in kb..(kb + MUL_BLOCK).min(n) {
+                        let a = &self.body[s * n + k];
+                        let rhs_str = &rhs.body[k * m + rb..k * m + r_end];
+                        for (e, b) in out_str[rb..r_end].iter_mut().zip(rhs_str) {
+                            *e = e.clone() + a.clone() * b.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn pow(&self, pow: usize) -> Result<Self, MatrixError> {
+        //! Matrix exponentiation, m^0 is the identity matrix.
+        //! See [Matrix::powi] for negative and [Matrix::powf] for fractional exponents.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 1.0, 2.0,
+        //!     3.0, -4.0, 5.0,
+        //!     6.0, 7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! let m = m.pow(3).unwrap();
+        //!
+        //! println!("m^3 = {}", m);
+        //! // m^3 = Matrix (3x3) = [
+        //! //  187 133  271
+        //! //  339 -13  520
+        //! //  843 716 1208
+        //! // ]
+        //! ```
+
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+        // Binary exponentiation: m^pow = product of m^(2^k) for the set bits of pow.
+        let mut res = Self::identity(self.rows)?;
+        let mut base = self.clone();
+        let mut pow = pow;
+        while pow > 0 {
+            if pow & 1 == 1 {
+                res = res.mul_by_ref(&base)?
+            }
+            pow >>= 1;
+            if pow > 0 {
+                base = base.mul_by_ref(&base)?
+            }
+        }
+        Ok(res)
+    }
+
+    pub fn powi(&self, pow: i64) -> Result<Self, MatrixError> {
+        //! Matrix exponentiation with an integer exponent, m^-n = (m^-1)^n.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     2.0, 1.0,
+        //!     1.0, 1.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m^-2 = {}", m.powi(-2).unwrap());
+        //! // m^-2 = Matrix (2x2) = [
+        //! //   2 -3
+        //! //  -3  5
+        //! // ]
+        //! ```
+
+        if pow >= 0 {
+            return self.pow(pow as usize)
+        }
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+        self.inverse()
+            .ok_or(MatrixError::Singular)?
+            .pow(pow.unsigned_abs() as usize)
+    }
+
+    pub fn add_by_ref(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Matrix addition by references.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(3, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //!     7.0, 8.0, 9.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(3, 3, vec![
+        //!     1.0, 1.0, 2.0,
+        //!     3.0, -4.0, 5.0,
+        //!     6.0, 7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m1 + m2 = {}", m1.add_by_ref(&m2).unwrap());
+        //! println!("m1 + m2 = {}", m1 + m2);
+        //! // m1 + m2 = Matrix (3x3) = [
+        //! //   2  3  5
+        //! //   7  1 11
+        //! //  13 15 17
+        //! // ]
+        //! // m1 + m2 = Matrix (3x3) = [
+        //! //   2  3  5
+        //! //   7  1 11
+        //! //  13 15 17
+        //! // ]
+        //! ```
+
+        self._check_same_dimensions(rhs)?;
+
+        let new_m: Vec<T> = self.body
+            .iter()
+            .enumerate()
+            .map(|(i, e)| e.clone() + rhs.body[i].clone())
+            .collect();
+
+        Self::new(self.strings, self.rows, new_m)
+    }
+
+    pub fn sub_by_ref(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Matrix subtraction by references.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(3, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //!     7.0, 8.0, 9.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(3, 3, vec![
+        //!     1.0, 1.0, 2.0,
+        //!     3.0, -4.0, 5.0,
+        //!     6.0, 7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m1 - m2 = {}", m1.sub_by_ref(&m2).unwrap());
+        //! println!("m1 - m2 = {}", m1 - m2);
+        //! // m1 - m2 = Matrix (3x3) = [
+        //! //  0 1 1
+        //! //  1 9 1
+        //! //  1 1 1
+        //! // ]
+        //! // m1 - m2 = Matrix (3x3) = [
+        //! //  0 1 1
+        //! //  1 9 1
+        //! //  1 1 1
+        //! // ]
+        //! ```
+
+        self._check_same_dimensions(rhs)?;
+
+        let new_m: Vec<T> = self.body
+            .iter()
+            .enumerate()
+            .map(|(i, e)| e.clone() - rhs.body[i].clone())
+            .collect();
+
+        Self::new(self.strings, self.rows, new_m)
+    }
+
+    pub fn transpose(&self) -> Self {
+        //! Matrix transposition.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 1.0, 2.0,
+        //!     3.0, -4.0, 5.0,
+        //!     6.0, 7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! println!("Transposed m = {}", m.transpose());
+        //! // Transposed m = Matrix (3x3) = [
+        //! //  1  3 6
+        //! //  1 -4 7
+        //! //  2  5 8
+        //! // ]
+        //! ```
+
+        let mut new_body = self.body.to_vec();
+        for (i, e) in self.body.iter().enumerate(){
+            new_body[ i / self.rows + self.strings * (i % self.rows) ] = e.clone()
+        }
+        Self::new(self.rows, self.strings, new_body).unwrap()
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        //! Matrix inversion (LU decomposition for real and complex numbers, cofactor matrix for symbolic Im).
+        //! [Matrix::inverse_checked] also rejects ill-conditioned matrices.
+        //! # Example
+        //! ```
+        //!
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 1.0, 2.0,
+        //!     3.0, -4.0, 5.0,
+        //!     6.0, 7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! let im = m.inverse().unwrap();
+        //!
+        //! println!("im = {:.4}", im);
+        //! // im = Matrix (3x3) = [
+        //! //  -2.3103  0.2069  0.4483
+        //! //   0.2069 -0.1379  0.0345
+        //! //   1.5517 -0.0345 -0.2414
+        //! // ]
+        //! ```
+
+        if self.rows != self.strings { return None }
+
+        T::matrix_inverse(self)
+    }
+
+    pub fn mul_num(&self, num: T) -> Self {
+        //! Matrix multiplication by number.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     1.0, 2.0, 3.0,
+        //!     5.0, 2.0, 1.0,
+        //! ]).unwrap();
+        //!
+        //! println!("m * 2 = {:?}", m.mul_num(2.0));
+        //! // m * 2 = Matrix { strings: 3, rows: 3, body: [2.0, 4.0, 6.0, 2.0, 4.0, 6.0, 10.0, 4.0, 2.0] }
+        //! ```
+
+        let new_m: Vec<T> = self.body
+            .iter()
+            .map(|e| e.clone() * num.clone())
+            .collect();
+
+        Self::new(self.strings, self.rows, new_m).unwrap()
+    }
+
+    pub fn cofactor_matrix(&self) -> Option<Self> {
+        //! Cofactor matrix.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 1.0, 2.0,
+        //!     3.0, -4.0, 5.0,
+        //!     6.0, 7.0, 8.0,
+        //! ]).unwrap();
+        //!
+        //! println!("Cofactor matrix of m = {:.1}", m.cofactor_matrix().unwrap());
+        //! // Cofactor matrix of m = Matrix (3x3) = [
+        //! //  -67.0  6.0 45.0
+        //! //    6.0 -4.0 -1.0
+        //! //   13.0  1.0 -7.0
+        //! // ]
+        //! ```
+
+        if self.rows != self.strings { return None }
+        if self.body.len() == 1 { return Some(Self::new(1, 1, vec![T::one()]).unwrap()) }
+
+        let mut ads = Vec::<T>::new();
+        self._cofactor_matrix(&mut ads);
+        let size = ads.len().isqrt();
+        Some(Self::new(size, size, ads).unwrap())
+    }
+
+    fn _cofactor_matrix(&self, ads: &mut Vec<T>) {
+        let mut minor = Box::new(T::zero());
+        if self.body.len() >= 4 {
+            for s in 0..self.strings {
+                for r in 0..self.rows {
+                    let ad = T::from_f64((-1_f64).powi((r+1 + s+1) as i32));
+                    if self.body.len() > 4 {
+                        let new_m = self._sub_matrix(s, r);
+                        *minor = new_m.det();
+                    } else {
+                        *minor = self.body[self.body.len()-1 - s * self.strings - r].clone();
+                    }
+                    let d = ad * (*minor).clone();
+                    ads.push(d);
+                }
+            }
+        }
+    }
+
+    pub fn slae(&self, d: &[T]) -> Result<Option<Vector<T>>, MatrixError> {
+        //! SLAE (System of Linear (Algebraic) Equations) (LU decomposition for real and complex numbers, Cramer's rule for symbolic Im).
+        //! Symmetric positive definite systems are solved faster by [Matrix::solve_spd].
+        //! [Matrix::slae_checked] also rejects ill-conditioned matrices.
+        //! Singular and non-square systems are solved by [Matrix::solve_general].
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 4.0, 2.0,
+        //!     2.0, -6.0, -2.0,
+        //!     1.0, 5.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! let d = &[1.0, 3.0, 2.0];
+        //!
+        //! for (i, x) in m.slae(d).unwrap().unwrap().iter().enumerate() {
+        //!     println!("x{} = {:?}", i+1, x);
+        //! }
+        //! // x1 = 2.0
+        //! // x2 = 1.0
+        //! // x3 = -2.5
+        //! ```
+
+        if self.rows != self.strings { return Ok(None) }
+        if d.len() != self.strings { return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (d.len(), 1) }) }
+
+        Ok(T::matrix_slae(self, d).map(Vector::from))
+    }
+
+    pub fn solve(&self, d: &[T]) -> Result<Vector<T>, MatrixError> {
+        //! Solution of the SLAE M * x = d as a [Vector], an error for a singular or non-square matrix.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::vector_algebra::Vector;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 4.0, 2.0,
+        //!     2.0, -6.0, -2.0,
+        //!     1.0, 5.0, 2.0,
+        //! ]).unwrap();
+        //! let d = Vector::new(vec![1.0, 3.0, 2.0]);
+        //!
+        //! let x = m.solve(&d).unwrap();
+        //! println!("x = {}", x);
+        //! // x = Vector (3) = [2, 1, -2.5]
+        //! assert_eq!(&m * &x, d);
+        //! ```
+
+        if self.rows != self.strings {
+            return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows })
+        }
+        self.slae(d)?.ok_or(MatrixError::Singular)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    pub(crate) fn _f64(&self) -> Cow<'_, Matrix> {
+        // The real algorithms work in f64.
+        T::matrix_to_f64(self)
+    }
+}
+
+impl Matrix<f32> {
+    pub(crate) fn to_f64(&self) -> Matrix {
+        Matrix::new(self.strings, self.rows, self.body.iter().map(|e| *e as f64).collect()).unwrap()
+    }
+}
+
+impl Matrix {
+    pub(crate) fn to_f32(&self) -> Matrix<f32> {
+        Matrix::new(self.strings, self.rows, self.body.iter().map(|e| *e as f32).collect()).unwrap()
+    }
+}
@@ -0,0 +1,170 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+use crate::matrices::lu::PackedLU;
+use crate::vector_algebra::Vector;
+
+
+impl<T: Real> Matrix<T> {
+    pub fn norm_1(&self) -> T {
+        //! 1-norm, the largest sum of absolute values of a row (column).
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, -2.0,
+        //!     -3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! println!("1: {}, inf: {}, F: {:.4}, 2: {:.4}", m.norm_1(), m.norm_inf(), m.norm_fro(), m.norm_2().unwrap());
+        //! // 1: 6, inf: 7, F: 5.4772, 2: 5.4650
+        //! ```
+
+        T::from_f64(self._f64()._norm_1())
+    }
+
+    pub fn norm_inf(&self) -> T {
+        //! Infinity norm, the largest sum of absolute values of a string.
+
+        T::from_f64(self._f64()._norm_inf())
+    }
+
+    pub fn norm_fro(&self) -> T {
+        //! Frobenius norm, the square root of the sum of squares of all elements.
+
+        T::from_f64(self._f64()._norm_fro())
+    }
+
+    pub fn norm_2(&self) -> Result<T, MatrixError> {
+        //! Spectral norm, the largest singular value.
+
+        Ok(T::from_f64(self._f64()._norm_2()?))
+    }
+
+    pub fn cond_est(&self) -> T {
+        //! Estimate of the condition number in the 1-norm, |A| * |A^-1| (Hager's method with the LU decomposition).
+        //! Much cheaper than [Matrix::cond], as A^-1 is not computed.
+        //! Returns 'inf' for singular and non-square matrices.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 1.0,
+        //!     1.0, 1.0001,
+        //! ]).unwrap();
+        //!
+        //! println!("cond = {:.0}", m.cond_est());
+        //! // cond = 40004
+        //! ```
+
+        T::from_f64(self._f64()._cond_est())
+    }
+
+    pub fn inverse_checked(&self, max_cond: f64) -> Result<Self, MatrixError> {
+        //! Matrix inversion, which fails if the matrix is singular or its condition number
+        //! estimate ([Matrix::cond_est]) exceeds max_cond.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 1.0,
+        //!     1.0, 1.0 + 1e-12,
+        //! ]).unwrap();
+        //!
+        //! println!("{}", m.inverse_checked(1e10).unwrap_err());
+        //! // The matrix is ill-conditioned (cond = 4.000e12).
+        //! ```
+
+        if self.rows != self.strings { return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows }) }
+
+        self._f64()._check_cond(max_cond)?;
+        self.inverse().ok_or(MatrixError::Singular)
+    }
+
+    pub fn slae_checked(&self, d: &[T], max_cond: f64) -> Result<Vector<T>, MatrixError> {
+        //! SLAE, which fails if the matrix is singular or its condition number
+        //! estimate ([Matrix::cond_est]) exceeds max_cond.
+
+        if self.rows != self.strings { return Err(MatrixError::NotSquare { strings: self.strings, rows: self.rows }) }
+
+        self._f64()._check_cond(max_cond)?;
+        self.slae(d)?.ok_or(MatrixError::Singular)
+    }
+}
+
+
+impl Matrix {
+    pub(crate) fn _norm_1(&self) -> f64 {
+        (0..self.rows)
+            .map(|r| self.body.iter().skip(r).step_by(self.rows).map(|e| e.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    fn _norm_inf(&self) -> f64 {
+        self.body
+            .chunks(self.rows)
+            .map(|str| str.iter().map(|e| e.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    fn _norm_fro(&self) -> f64 {
+        self.body.iter().map(|e| e * e).sum::<f64>().sqrt()
+    }
+
+    fn _norm_2(&self) -> Result<f64, MatrixError> {
+        self.svd().map(|(_, s, _)| s[0])
+    }
+
+    fn _cond_est(&self) -> f64 {
+        if self.rows != self.strings { return f64::INFINITY }
+
+        let lu = self._lu();
+        if lu.is_singular { return f64::INFINITY }
+
+        self.norm_1() * Self::_inverse_norm_1_est(&lu, self.rows)
+    }
+
+    fn _inverse_norm_1_est(lu: &PackedLU, n: usize) -> f64 {
+        let norm_1 = |v: &[f64]| v.iter().map(|e| e.abs()).sum::<f64>();
+
+        let mut x = vec![1.0 / n as f64; n];
+        let mut est = 0.0;
+        for _ in 0..5 {
+            let y = lu.solve(&x);
+            est = norm_1(&y);
+
+            let xi = y.iter().map(|e| if *e >= 0.0 { 1.0 } else { -1.0 }).collect::<Vec<f64>>();
+            let z = lu.solve_t(&xi);
+            let (j, z_max) = z
+                .iter()
+                .enumerate()
+                .fold((0, 0.0), |(j, m), (i, e)| if e.abs() > m { (i, e.abs()) } else { (j, m) });
+            let zx: f64 = z.iter().zip(&x).map(|(a, b)| a * b).sum();
+            if z_max <= zx { break }
+
+            x = vec![0.0; n];
+            x[j] = 1.0;
+        }
+
+        // Higham's alternative vector catches the cases where the iteration is misled.
+        let b = (0..n)
+            .map(|i| {
+                let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                sign * (1.0 + i as f64 / (n.max(2) - 1) as f64)
+            })
+            .collect::<Vec<f64>>();
+        let alt = 2.0 * norm_1(&lu.solve(&b)) / (3 * n) as f64;
+
+        f64::max(est, alt)
+    }
+
+    fn _check_cond(&self, max_cond: f64) -> Result<(), MatrixError> {
+        let cond = self.cond_est();
+        if cond.is_infinite() { return Err(MatrixError::Singular) }
+        if cond > max_cond { return Err(MatrixError::IllConditioned { cond }) }
+        Ok(())
+    }
+}
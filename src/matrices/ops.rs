@@ -0,0 +1,253 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::im::core::Im;
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Scalar;
+use crate::vector_algebra::Vector;
+
+// The operators panic if the dimensions do not match, checked_* return the error instead.
+
+impl<T: Scalar> Mul for Matrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_by_ref(&rhs).unwrap()
+    }
+}
+impl<T: Scalar> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_by_ref(rhs).unwrap()
+    }
+}
+impl<T: Scalar> Add for Matrix<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_by_ref(&rhs).unwrap()
+    }
+}
+impl<T: Scalar> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_by_ref(rhs).unwrap()
+    }
+}
+impl<T: Scalar> Sub for Matrix<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub_by_ref(&rhs).unwrap()
+    }
+}
+impl<T: Scalar> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub_by_ref(rhs).unwrap()
+    }
+}
+
+
+impl<T: Scalar> Mul<T> for Matrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.mul_num(rhs)
+    }
+}
+impl<T: Scalar> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.mul_num(rhs)
+    }
+}
+impl Mul<Matrix<f64>> for f64 {
+    type Output = Matrix<f64>;
+
+    fn mul(self, rhs: Matrix<f64>) -> Self::Output {
+        rhs.mul_num(self)
+    }
+}
+impl Mul<&Matrix<f64>> for f64 {
+    type Output = Matrix<f64>;
+
+    fn mul(self, rhs: &Matrix<f64>) -> Self::Output {
+        rhs.mul_num(self)
+    }
+}
+impl Mul<Matrix<f32>> for f32 {
+    type Output = Matrix<f32>;
+
+    fn mul(self, rhs: Matrix<f32>) -> Self::Output {
+        rhs.mul_num(self)
+    }
+}
+impl Mul<&Matrix<f32>> for f32 {
+    type Output = Matrix<f32>;
+
+    fn mul(self, rhs: &Matrix<f32>) -> Self::Output {
+        rhs.mul_num(self)
+    }
+}
+impl Mul<Matrix<Im>> for Im {
+    type Output = Matrix<Im>;
+
+    fn mul(self, rhs: Matrix<Im>) -> Self::Output {
+        // num * e, the order of the expression is kept.
+        let body = rhs.body.into_iter().map(|e| self.clone() * e).collect();
+        Matrix::new(rhs.strings, rhs.rows, body).unwrap()
+    }
+}
+impl Mul<&Matrix<Im>> for Im {
+    type Output = Matrix<Im>;
+
+    fn mul(self, rhs: &Matrix<Im>) -> Self::Output {
+        self * rhs.clone()
+    }
+}
+
+
+impl<T: Scalar> Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        self.mul_vector(rhs).unwrap()
+    }
+}
+impl<T: Scalar> Mul<Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        self.mul_vector(&rhs).unwrap()
+    }
+}
+
+
+impl<T: Scalar> Neg for Matrix<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+impl<T: Scalar> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        let body = self.body.iter().map(|e| T::zero() - e.clone()).collect();
+        Matrix::new(self.strings, self.rows, body).unwrap()
+    }
+}
+
+
+impl<T: Scalar> AddAssign<&Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, rhs: &Self) {
+        self._check_same_dimensions(rhs).unwrap();
+        self.body.iter_mut().zip(&rhs.body).for_each(|(a, b)| *a = a.clone() + b.clone());
+    }
+}
+impl<T: Scalar> AddAssign for Matrix<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs
+    }
+}
+impl<T: Scalar> SubAssign<&Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self._check_same_dimensions(rhs).unwrap();
+        self.body.iter_mut().zip(&rhs.body).for_each(|(a, b)| *a = a.clone() - b.clone());
+    }
+}
+impl<T: Scalar> SubAssign for Matrix<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs
+    }
+}
+impl<T: Scalar> MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = self.mul_by_ref(rhs).unwrap()
+    }
+}
+impl<T: Scalar> MulAssign for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs
+    }
+}
+impl<T: Scalar> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.body.iter_mut().for_each(|e| *e = e.clone() * rhs.clone());
+    }
+}
+
+
+impl<T: Scalar> Matrix<T> {
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Matrix addition, which returns the error instead of panicking as the + operator does.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m1 = Matrix::new(2, 2, vec![
+        //!     1.0, 2.0,
+        //!     3.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! let m2 = Matrix::new(1, 2, vec![1.0, 1.0]).unwrap();
+        //!
+        //! println!("{}", m1.checked_add(&m2).unwrap_err());
+        //! // Matrix dimensions do not match (2x2 and 1x2).
+        //!
+        //! let m = -(&m1 + &m1) * 0.5 + 2.0 * m1;
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 2, rows: 2, body: [1.0, 2.0, 3.0, 4.0] }
+        //! ```
+
+        self.add_by_ref(rhs)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        self.sub_by_ref(rhs)
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Matrix multiplication, which returns the error instead of panicking as the * operator does
+        //! (see [Matrix::mul_by_ref]).
+
+        self.mul_by_ref(rhs)
+    }
+
+    pub fn mul_vector(&self, v: &[T]) -> Result<Vector<T>, MatrixError> {
+        //! Matrix-vector product M * v, the vector is a column.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::vector_algebra::Vector;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //! ]).unwrap();
+        //! let v = Vector::new(vec![1.0, 0.0, -1.0]);
+        //!
+        //! println!("m * v = {}", m.mul_vector(&v).unwrap());
+        //! // m * v = Vector (2) = [-2, -2]
+        //! ```
+
+        if v.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (v.len(), 1) })
+        }
+        Ok((0..self.strings)
+            .map(|s| self.row(s).iter().zip(v).map(|(a, b)| a.clone() * b.clone()).sum())
+            .collect())
+    }
+
+    pub(crate) fn _check_same_dimensions(&self, rhs: &Self) -> Result<(), MatrixError> {
+        if self.rows != rhs.rows || self.strings != rhs.strings {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (rhs.strings, rhs.rows) })
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::matrices::{noise_level, Matrix};
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+use crate::vector_algebra::Vector;
+
+
+pub(crate) struct PackedQR {
+    rows: usize,
+    r: Vec<f64>,
+    v: Vec<Vec<f64>>,
+    pub(crate) is_rank_deficient: bool,
+}
+impl PackedQR {
+    pub(crate) fn apply_qt(&self, d: &mut [f64]) {
+        // Q^T = H_k * ... * H_1, H = E - 2*v*v^T
+        for (k, v) in self.v.iter().enumerate() {
+            reflect(v, &mut d[k..]);
+        }
+    }
+
+    pub(crate) fn apply_q(&self, d: &mut [f64]) {
+        for (k, v) in self.v.iter().enumerate().rev() {
+            reflect(v, &mut d[k..]);
+        }
+    }
+
+    pub(crate) fn solve_r(&self, y: &[f64]) -> Vec<f64> {
+        let n = self.rows;
+        let mut x = y[..n].to_vec();
+        for s in (0..n).rev() {
+            for r in s+1..n {
+                x[s] -= self.r[s * n + r] * x[r];
+            }
+            x[s] /= self.r[s * n + s];
+        }
+        x
+    }
+}
+
+fn reflect(v: &[f64], d: &mut [f64]) {
+    let dot: f64 = v.iter().zip(d.iter()).map(|(a, b)| a * b).sum();
+    if dot == 0.0 { return }
+    d.iter_mut().zip(v.iter()).for_each(|(e, a)| *e -= 2.0 * dot * a);
+}
+
+
+impl<T: Real> Matrix<T> {
+    pub fn qr(&self) -> Result<(Self, Self), MatrixError> {
+        //! QR decomposition (Householder reflections): A = Q*R.
+        //!
+        //! Q - matrix (strings x k) with orthonormal columns
+        //!
+        //! R - upper triangular matrix (k x rows)
+        //!
+        //! k - the smaller of the matrix dimensions
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 2, vec![
+        //!     3.0, 1.0,
+        //!     4.0, 2.0,
+        //!     0.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! let (q, r) = m.qr().unwrap();
+        //!
+        //! println!("R = {:.4?}", r);
+        //! // R = Matrix { strings: 2, rows: 2, body: [-5.0000, -2.2000, 0.0000, -2.0396] }
+        //! println!("Q*R = {:.4?}", q * r);
+        //! // Q*R = Matrix { strings: 3, rows: 2, body: [3.0000, 1.0000, 4.0000, 2.0000, 0.0000, 2.0000] }
+        //! ```
+
+        let (q, r) = self._f64()._qr_factors()?;
+        Ok((T::matrix_from_f64(q), T::matrix_from_f64(r)))
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn lstsq(&self, d: &[T]) -> Result<Option<(Vector<T>, T)>, MatrixError> {
+        //! Least squares solution of the overdetermined system A*x = d (QR decomposition).
+        //! Returns x, which minimizes |A*x - d|, and the residual norm |A*x - d|.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! // y = a + b*x for the points (0, 1), (1, 3), (2, 4)
+        //! let m = Matrix::new(3, 2, vec![
+        //!     1.0, 0.0,
+        //!     1.0, 1.0,
+        //!     1.0, 2.0,
+        //! ]).unwrap();
+        //!
+        //! let (x, residual) = m.lstsq(&[1.0, 3.0, 4.0]).unwrap().unwrap();
+        //!
+        //! println!("a = {:.4}, b = {:.4}, residual = {:.4}", x[0], x[1], residual);
+        //! // a = 1.1667, b = 1.5000, residual = 0.4082
+        //! ```
+
+        Ok(self._f64()._lstsq(&T::slice_to_f64(d))?
+            .map(|(x, residual)| (Vector::from(T::vec_from_f64(x)), T::from_f64(residual))))
+    }
+}
+
+
+impl Matrix {
+    fn _qr_factors(&self) -> Result<(Self, Self), MatrixError> {
+        let packed = self._qr();
+        let k = packed.v.len();
+
+        let mut q = vec![0.0; self.strings * k];
+        for c in 0..k {
+            let mut e = vec![0.0; self.strings];
+            e[c] = 1.0;
+            packed.apply_q(&mut e);
+            for (s, x) in e.iter().enumerate() {
+                q[s * k + c] = *x
+            }
+        }
+        let r = packed.r[..k * self.rows].to_vec();
+
+        Ok((Self::new(self.strings, k, q)?, Self::new(k, self.rows, r)?))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn _lstsq(&self, d: &[f64]) -> Result<Option<(Vec<f64>, f64)>, MatrixError> {
+        if d.len() != self.strings { return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (d.len(), 1) }) }
+        if self.strings < self.rows { return Ok(None) }
+
+        let packed = self._qr();
+        if packed.is_rank_deficient { return Ok(None) }
+
+        let mut y = d.to_vec();
+        packed.apply_qt(&mut y);
+
+        let residual = y[self.rows..]
+            .iter()
+            .map(|e| e * e)
+            .sum::<f64>()
+            .sqrt();
+
+        Ok(Some((packed.solve_r(&y), residual)))
+    }
+
+    pub(crate) fn _qr(&self) -> PackedQR {
+        let (m, n) = (self.strings, self.rows);
+        let mut r = self.body.to_vec();
+        let mut v = Vec::<Vec<f64>>::new();
+
+        let tolerance = noise_level(m.max(n), &self.body);
+        let mut is_rank_deficient = false;
+
+        for k in 0..m.min(n) {
+            let x: Vec<f64> = (k..m).map(|s| r[s * n + k]).collect();
+            let norm = x.iter().map(|e| e * e).sum::<f64>().sqrt();
+            let alpha = if x[0] > 0.0 { -norm } else { norm };
+
+            let mut vk = x;
+            vk[0] -= alpha;
+            let v_norm = vk.iter().map(|e| e * e).sum::<f64>().sqrt();
+            if v_norm != 0.0 {
+                vk.iter_mut().for_each(|e| *e /= v_norm);
+            }
+
+            for c in k..n {
+                let mut col: Vec<f64> = (k..m).map(|s| r[s * n + c]).collect();
+                reflect(&vk, &mut col);
+                for (i, e) in col.iter().enumerate() {
+                    r[(k + i) * n + c] = *e
+                }
+            }
+            for s in k+1..m {
+                r[s * n + k] = 0.0
+            }
+
+            if r[k * n + k].abs() <= tolerance { is_rank_deficient = true }
+            v.push(vk);
+        }
+
+        PackedQR { rows: n, r, v, is_rank_deficient }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::matrices::{noise_level, Matrix};
+use crate::matrices::scalar::Real;
+
+
+impl<T: Real> Matrix<T> {
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        //! Reduced row echelon form (Gauss-Jordan elimination with partial pivoting).
+        //! Returns the RREF and the pivot rows (columns); their number is the rank of the matrix
+        //! (see also [Matrix::rank]).
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 4, vec![
+        //!     1.0, 2.0, 1.0, 4.0,
+        //!     2.0, 4.0, 0.0, 6.0,
+        //!     3.0, 6.0, 1.0, 10.0,
+        //! ]).unwrap();
+        //!
+        //! let (r, pivots) = m.rref();
+        //!
+        //! println!("pivots = {:?}", pivots);
+        //! // pivots = [0, 2]
+        //! println!("rref = {:.4?}", r);
+        //! // rref = Matrix { strings: 3, rows: 4, body: [1.0000, 2.0000, 0.0000, 3.0000, 0.0000, 0.0000, 1.0000, 1.0000, 0.0000, 0.0000, 0.0000, 0.0000] }
+        //! ```
+
+        let (r, pivots) = self._f64()._rref();
+        (T::matrix_from_f64(r), pivots)
+    }
+
+    pub fn null_space(&self) -> Option<Self> {
+        //! Basis of the null space, the solutions of A*x = 0, as the columns of the matrix.
+        //! Returns None if the null space is {0}.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     2.0, 4.0, 6.0,
+        //! ]).unwrap();
+        //!
+        //! println!("N(A) = {:?}", m.null_space().unwrap());
+        //! // N(A) = Matrix { strings: 3, rows: 2, body: [-2.0, -3.0, 1.0, 0.0, 0.0, 1.0] }
+        //! ```
+
+        self._f64()._null_space_basis().map(T::matrix_from_f64)
+    }
+
+    pub fn column_space(&self) -> Option<Self> {
+        //! Basis of the column space, the pivot columns of the matrix.
+        //! Returns None for the zero matrix.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     2.0, 4.0, 7.0,
+        //! ]).unwrap();
+        //!
+        //! println!("C(A) = {:?}", m.column_space().unwrap());
+        //! // C(A) = Matrix { strings: 2, rows: 2, body: [1.0, 3.0, 2.0, 7.0] }
+        //! ```
+
+        let (_, pivots) = self._f64()._rref();
+        if pivots.is_empty() { return None }
+
+        let body = (0..self.strings)
+            .flat_map(|s| pivots.iter().map(move |p| self.body[s * self.rows + p]))
+            .collect();
+        Some(Self::new(self.strings, pivots.len(), body).unwrap())
+    }
+}
+
+
+impl Matrix {
+    fn _rref(&self) -> (Self, Vec<usize>) {
+        let (m, n) = (self.strings, self.rows);
+        let mut a = self.body.to_vec();
+        let mut pivots = Vec::<usize>::new();
+
+        // Elements smaller than this are rounding noise.
+        let tolerance = noise_level(m.max(n), &self.body);
+
+        let mut s = 0;
+        for r in 0..n {
+            if s == m { break }
+
+            let p = (s..m)
+                .max_by(|i, j| a[*i * n + r].abs().total_cmp(&a[*j * n + r].abs()))
+                .unwrap();
+            if a[p * n + r].abs() <= tolerance {
+                (s..m).for_each(|i| a[i * n + r] = 0.0);
+                continue
+            }
+            for c in 0..n {
+                a.swap(s * n + c, p * n + c);
+            }
+
+            let pivot = a[s * n + r];
+            for c in 0..n {
+                a[s * n + c] /= pivot;
+            }
+            for i in (0..m).filter(|i| *i != s) {
+                let f = a[i * n + r];
+                if f == 0.0 { continue }
+                for c in 0..n {
+                    a[i * n + c] -= f * a[s * n + c];
+                }
+                a[i * n + r] = 0.0;
+            }
+
+            pivots.push(r);
+            s += 1;
+        }
+
+        (Self::new(m, n, a).unwrap(), pivots)
+    }
+
+    fn _null_space_basis(&self) -> Option<Self> {
+        let (r, pivots) = self.rref();
+        Self::_null_space(&r, &pivots, self.rows)
+    }
+
+    pub(crate) fn _null_space(r: &Self, pivots: &[usize], n: usize) -> Option<Self> {
+        // n - the number of unknowns, the first n rows (columns) of the RREF.
+        let free = (0..n).filter(|c| !pivots.contains(c)).collect::<Vec<usize>>();
+        if free.is_empty() { return None }
+
+        // x[free] = 1, x[pivot] = -rref[pivot string][free]
+        let k = free.len();
+        let mut body = vec![0.0; n * k];
+        for (j, f) in free.iter().enumerate() {
+            body[f * k + j] = 1.0;
+            for (s, p) in pivots.iter().enumerate() {
+                body[p * k + j] = 0.0 - r.body[s * r.rows + f];  // no -0.0
+            }
+        }
+        Some(Self::new(n, k, body).unwrap())
+    }
+}
@@ -0,0 +1,268 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Sub};
+use crate::im::core::Im;
+use crate::matrices::Matrix;
+use crate::matrices::eigen::{from_im, to_im, Complex};
+use crate::utils::AdvancedEQ;
+
+
+pub trait Scalar:
+    Clone + Default + Debug + Display + PartialEq + Sum + Send + Sync +
+    Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    //! Element type of the [Matrix]: f64, f32 or Im.
+    //!
+    //! The determinant, inversion and SLAE algorithms are chosen by the element type:
+    //! LU decomposition for real and complex numbers, cofactor expansion and Cramer's rule by default
+    //! and for Im values with mixed powers or products.
+    //! The decompositions, norms and matrix functions need a [Real] element type.
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_f64(n: f64) -> Self;
+    fn is_zero(&self) -> bool;
+    fn approx_eq(&self, other: &Self, precision: f64) -> bool;
+
+    fn to_text(&self, precision: Option<usize>) -> String {
+        // Element text of the matrix formatters and exporters.
+        match precision {
+            Some(p) => format!("{:.*}", p, self),
+            None => self.to_string(),
+        }
+    }
+
+    fn to_python(&self, precision: Option<usize>) -> String {
+        // Element text of the NumPy literal.
+        self.to_text(precision)
+    }
+
+    fn matrix_det(m: &Matrix<Self>) -> Self {
+        cofactor_det(m)
+    }
+
+    fn matrix_inverse(m: &Matrix<Self>) -> Option<Matrix<Self>> {
+        cofactor_inverse(m)
+    }
+
+    fn matrix_slae(m: &Matrix<Self>, d: &[Self]) -> Option<Vec<Self>> {
+        cramer_slae(m, d)
+    }
+}
+
+
+fn cofactor_det<T: Scalar>(m: &Matrix<T>) -> T {
+    // Cofactor expansion along the first row.
+    match m.body.len() {
+        1 => m.body[0].clone(),
+        4 => m.body[0].clone() * m.body[3].clone() - m.body[1].clone() * m.body[2].clone(),
+        _ => {
+            let mut det = T::zero();
+            for s in 0..m.strings {
+                let elem = m.body[s * m.rows].clone();
+                let minor = m._sub_matrix(s, 0).det();
+                det = if s % 2 == 0 { det + elem * minor } else { det - elem * minor };
+            }
+            det
+        }
+    }
+}
+
+
+fn cofactor_inverse<T: Scalar>(m: &Matrix<T>) -> Option<Matrix<T>> {
+    // inv_m = transposed CFM / det
+    let det = m.det();
+    if det.is_zero() { return None }
+
+    Some(
+        m.cofactor_matrix()?
+            .transpose()
+            .mul_num(T::one() / det)
+    )
+}
+
+
+fn cramer_slae<T: Scalar>(m: &Matrix<T>, d: &[T]) -> Option<Vec<T>> {
+    // Cramer's rule
+    let det = m.det();
+    if det.is_zero() { return None }
+
+    let mut res = Vec::<T>::new();
+    for r in 0..m.rows {
+        let mut new_body = m.body.to_vec();
+        for (i, e) in d.iter().enumerate() {
+            new_body[r + m.rows * i] = e.clone()
+        }
+        let x_m = Matrix::new(m.strings, m.rows, new_body).ok()?;
+        res.push(x_m.det() / det.clone());
+    }
+    Some(res)
+}
+
+
+pub trait Real: Scalar + Copy + PartialOrd {
+    //! Real element type of the [Matrix]: f64 or f32.
+    //!
+    //! The decompositions (lu, qr, cholesky, svd, eigen_sym, eigen), rref, norms, condition numbers
+    //! and matrix functions (expm, sqrtm, logm, powf) are computed in f64, f32 matrices are converted.
+    //! They are not available for Matrix<Im>.
+
+    fn to_f64(self) -> f64;
+
+    fn matrix_to_f64(m: &Matrix<Self>) -> Cow<'_, Matrix> {
+        Cow::Owned(Matrix::new(m.strings, m.rows, m.body.iter().map(|e| e.to_f64()).collect()).unwrap())
+    }
+
+    fn matrix_from_f64(m: Matrix) -> Matrix<Self> {
+        Matrix::new(m.strings, m.rows, m.body.into_iter().map(Self::from_f64).collect()).unwrap()
+    }
+
+    fn slice_to_f64(d: &[Self]) -> Cow<'_, [f64]> {
+        Cow::Owned(d.iter().map(|e| e.to_f64()).collect())
+    }
+
+    fn vec_from_f64(v: Vec<f64>) -> Vec<Self> {
+        v.into_iter().map(Self::from_f64).collect()
+    }
+}
+
+
+fn python_float<F: Copy + Debug + Display + Into<f64>>(n: F, precision: Option<usize>) -> String {
+    // Always with the decimal point, so NumPy does not take the array as integer.
+    let f: f64 = n.into();
+    match precision {
+        _ if f.is_nan() => "np.nan".to_string(),
+        _ if f.is_infinite() => if f > 0.0 { "np.inf".to_string() } else { "-np.inf".to_string() },
+        Some(0) => format!("{:.0}.0", n),
+        Some(p) => format!("{:.*}", p, n),
+        None => format!("{:?}", n),
+    }
+}
+
+impl Scalar for f64 {
+    fn zero() -> Self { 0.0 }
+    fn one() -> Self { 1.0 }
+    fn from_f64(n: f64) -> Self { n }
+    fn is_zero(&self) -> bool { *self == 0.0 }
+    fn approx_eq(&self, other: &Self, precision: f64) -> bool { self.is_equal(*other, precision) }
+    fn to_python(&self, precision: Option<usize>) -> String { python_float(*self, precision) }
+
+    fn matrix_det(m: &Matrix<Self>) -> Self {
+        m._lu().det()
+    }
+
+    fn matrix_inverse(m: &Matrix<Self>) -> Option<Matrix<Self>> {
+        // Solves A*X = E column by column.
+        let lu = m._lu();
+        if lu.is_singular { return None }
+
+        let mut new_body = vec![0.0; m.body.len()];
+        for r in 0..m.rows {
+            let mut e = vec![0.0; m.rows];
+            e[r] = 1.0;
+            for (s, x) in lu.solve(&e).iter().enumerate() {
+                new_body[s * m.rows + r] = *x
+            }
+        }
+        Matrix::new(m.strings, m.rows, new_body).ok()
+    }
+
+    fn matrix_slae(m: &Matrix<Self>, d: &[Self]) -> Option<Vec<Self>> {
+        let lu = m._lu();
+        if lu.is_singular { return None }
+
+        Some(lu.solve(d))
+    }
+}
+
+
+impl Real for f64 {
+    fn to_f64(self) -> f64 { self }
+
+    // No copies for f64.
+
+    fn matrix_to_f64(m: &Matrix<Self>) -> Cow<'_, Matrix> { Cow::Borrowed(m) }
+    fn matrix_from_f64(m: Matrix) -> Matrix<Self> { m }
+    fn slice_to_f64(d: &[Self]) -> Cow<'_, [f64]> { Cow::Borrowed(d) }
+    fn vec_from_f64(v: Vec<f64>) -> Vec<Self> { v }
+}
+
+
+impl Scalar for f32 {
+    fn zero() -> Self { 0.0 }
+    fn one() -> Self { 1.0 }
+    fn from_f64(n: f64) -> Self { n as f32 }
+    fn is_zero(&self) -> bool { *self == 0.0 }
+    fn approx_eq(&self, other: &Self, precision: f64) -> bool { self.is_equal(*other, precision) }
+    fn to_python(&self, precision: Option<usize>) -> String { python_float(*self, precision) }
+
+    // f32 matrices are solved in f64.
+
+    fn matrix_det(m: &Matrix<Self>) -> Self {
+        m.to_f64().det() as f32
+    }
+
+    fn matrix_inverse(m: &Matrix<Self>) -> Option<Matrix<Self>> {
+        m.to_f64().inverse().map(|e| e.to_f32())
+    }
+
+    fn matrix_slae(m: &Matrix<Self>, d: &[Self]) -> Option<Vec<Self>> {
+        let d = d.iter().map(|e| *e as f64).collect::<Vec<f64>>();
+        f64::matrix_slae(&m.to_f64(), &d).map(|x| x.iter().map(|e| *e as f32).collect())
+    }
+}
+
+
+impl Real for f32 {
+    fn to_f64(self) -> f64 { self as f64 }
+}
+
+
+impl Scalar for Im {
+    fn zero() -> Self { Im::new(0.0, 0.0) }
+    fn one() -> Self { Im::new(1.0, 0.0) }
+    fn from_f64(n: f64) -> Self { Im::new(n, 0.0) }
+    fn is_zero(&self) -> bool { Im::is_zero(self) }
+    fn approx_eq(&self, other: &Self, _precision: f64) -> bool { self == other }
+    fn to_python(&self, precision: Option<usize>) -> String {
+        match precision {
+            Some(p) => self.round(p).format_python(),
+            None => self.format_python(),
+        }
+    }
+
+    // Complex LU if every element is a + bi.
+
+    fn matrix_det(m: &Matrix<Self>) -> Self {
+        match m._complex_lu() {
+            Some(lu) => to_im(lu.det()),
+            None => cofactor_det(m),
+        }
+    }
+
+    fn matrix_inverse(m: &Matrix<Self>) -> Option<Matrix<Self>> {
+        let Some(lu) = m._complex_lu() else { return cofactor_inverse(m) };
+        if lu.is_singular { return None }
+
+        let mut new_body = vec![Im::zero(); m.body.len()];
+        for r in 0..m.rows {
+            let mut e = vec![(0.0, 0.0); m.rows];
+            e[r] = (1.0, 0.0);
+            for (s, x) in lu.solve(&e).into_iter().enumerate() {
+                new_body[s * m.rows + r] = to_im(x)
+            }
+        }
+        Matrix::new(m.strings, m.rows, new_body).ok()
+    }
+
+    fn matrix_slae(m: &Matrix<Self>, d: &[Self]) -> Option<Vec<Self>> {
+        let Some(lu) = m._complex_lu() else { return cramer_slae(m, d) };
+        let Some(c) = d.iter().map(from_im).collect::<Option<Vec<Complex>>>() else {
+            return cramer_slae(m, d)
+        };
+        if lu.is_singular { return None }
+
+        Some(lu.solve(&c).into_iter().map(to_im).collect())
+    }
+}
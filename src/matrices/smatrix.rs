@@ -0,0 +1,255 @@
+use std::ops::{Add, Mul, Sub};
+use crate::matrices::{noise_level, Matrix};
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+use crate::utils::default::PRECISION;
+
+
+/// Stack-allocated matrix with R strings and C rows of f64 (f32) elements.
+/// Dimensions are checked at compile time, so operations don't return Result.
+/// # Example
+/// ```
+/// use xmath::matrices::smatrix::SMatrix;
+///
+/// let m1 = SMatrix::new([
+///     [1.0, 2.0, 3.0],
+///     [4.0, 5.0, 6.0],
+/// ]);
+///
+/// let m2 = SMatrix::new([
+///     [1.0, 2.0],
+///     [3.0, 4.0],
+///     [5.0, 6.0],
+/// ]);
+///
+/// println!("m1 * m2 = {:?}", m1 * m2);
+/// // m1 * m2 = SMatrix { body: [[22.0, 28.0], [49.0, 64.0]] }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SMatrix<const R: usize, const C: usize, T = f64> {
+    body: [[T; C]; R],
+}
+impl<const R: usize, const C: usize, T: Real> Default for SMatrix<R, C, T> {
+    fn default() -> Self {
+        Self { body: [[T::zero(); C]; R] }
+    }
+}
+impl<const R: usize, const C: usize, T: Real> PartialEq for SMatrix<R, C, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.body
+            .iter()
+            .flatten()
+            .zip(other.body.iter().flatten())
+            .all(|(a, b)| a.approx_eq(b, PRECISION))
+    }
+}
+impl<const R: usize, const K: usize, const C: usize, T: Real> Mul<SMatrix<K, C, T>> for SMatrix<R, K, T> {
+    type Output = SMatrix<R, C, T>;
+
+    fn mul(self, rhs: SMatrix<K, C, T>) -> Self::Output {
+        let mut body = [[T::zero(); C]; R];
+        for (s, str) in body.iter_mut().enumerate() {
+            for (r, e) in str.iter_mut().enumerate() {
+                *e = (0..K).map(|k| self.body[s][k] * rhs.body[k][r]).sum();
+            }
+        }
+        SMatrix { body }
+    }
+}
+impl<const R: usize, const C: usize, T: Real> Add for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.body
+            .iter_mut()
+            .flatten()
+            .zip(rhs.body.iter().flatten())
+            .for_each(|(a, b)| *a = *a + *b);
+        self
+    }
+}
+impl<const R: usize, const C: usize, T: Real> Sub for SMatrix<R, C, T> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.body
+            .iter_mut()
+            .flatten()
+            .zip(rhs.body.iter().flatten())
+            .for_each(|(a, b)| *a = *a - *b);
+        self
+    }
+}
+impl<const R: usize, const C: usize, T: Real> From<SMatrix<R, C, T>> for Matrix<T> {
+    fn from(m: SMatrix<R, C, T>) -> Self {
+        Matrix::new(R, C, m.body.iter().flatten().copied().collect()).unwrap()
+    }
+}
+impl<const R: usize, const C: usize, T: Real> TryFrom<&Matrix<T>> for SMatrix<R, C, T> {
+    type Error = MatrixError;
+
+    fn try_from(m: &Matrix<T>) -> Result<Self, Self::Error> {
+        if m.strings != R || m.rows != C { return Err(MatrixError::DimensionMismatch { left: (R, C), right: (m.strings, m.rows) }) }
+
+        let mut body = [[T::zero(); C]; R];
+        for (s, str) in body.iter_mut().enumerate() {
+            str.copy_from_slice(&m.body[s * C..(s + 1) * C]);
+        }
+        Ok(Self { body })
+    }
+}
+impl<const R: usize, const C: usize, T: Real> TryFrom<Matrix<T>> for SMatrix<R, C, T> {
+    type Error = MatrixError;
+
+    fn try_from(m: Matrix<T>) -> Result<Self, Self::Error> {
+        Self::try_from(&m)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Real> SMatrix<R, C, T> {
+    pub fn new(body: [[T; C]; R]) -> Self {
+        Self { body }
+    }
+
+    pub fn body(&self) -> &[[T; C]; R] {
+        &self.body
+    }
+
+    pub fn transpose(&self) -> SMatrix<C, R, T> {
+        //! Matrix transposition.
+        //! # Example
+        //!```
+        //! use xmath::matrices::smatrix::SMatrix;
+        //!
+        //! let m = SMatrix::new([
+        //!     [1.0, 2.0, 3.0],
+        //!     [4.0, 5.0, 6.0],
+        //! ]);
+        //!
+        //! println!("Transposed m = {:?}", m.transpose());
+        //! // Transposed m = SMatrix { body: [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]] }
+        //! ```
+
+        let mut body = [[T::zero(); R]; C];
+        for (s, str) in self.body.iter().enumerate() {
+            for (r, e) in str.iter().enumerate() {
+                body[r][s] = *e
+            }
+        }
+        SMatrix { body }
+    }
+
+    fn _f64(&self) -> SMatrix<R, C> {
+        // The determinant and inversion work in f64.
+        SMatrix { body: self.body.map(|str| str.map(T::to_f64)) }
+    }
+}
+
+impl<const N: usize, T: Real> SMatrix<N, N, T> {
+    pub fn identity() -> Self {
+        let mut body = [[T::zero(); N]; N];
+        for (i, str) in body.iter_mut().enumerate() {
+            str[i] = T::one()
+        }
+        Self { body }
+    }
+
+    pub fn det(&self) -> T {
+        //! The matrix determinant (LU decomposition).
+        //! # Example
+        //!```
+        //! use xmath::matrices::smatrix::SMatrix;
+        //!
+        //! let m = SMatrix::new([
+        //!     [1.0, 4.0, 2.0],
+        //!     [2.0, -6.0, -2.0],
+        //!     [1.0, 5.0, 2.0],
+        //! ]);
+        //!
+        //! println!("det = {:.4}", m.det());
+        //! // det = 6.0000
+        //! ```
+
+        T::from_f64(self._f64()._det())
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        //! Matrix inversion (Gauss-Jordan elimination).
+        //! # Example
+        //! ```
+        //! use xmath::matrices::smatrix::SMatrix;
+        //!
+        //! let m = SMatrix::new([
+        //!     [4.0, 7.0],
+        //!     [2.0, 6.0],
+        //! ]);
+        //!
+        //! println!("im = {:.4?}", m.inverse().unwrap());
+        //! // im = SMatrix { body: [[0.6000, -0.7000], [-0.2000, 0.4000]] }
+        //! ```
+
+        self._f64()._inverse().map(|m| Self { body: m.body.map(|str| str.map(T::from_f64)) })
+    }
+}
+
+impl<const N: usize> SMatrix<N, N> {
+    fn _det(&self) -> f64 {
+        let mut a = self.body;
+        let mut det = 1.0;
+
+        // The product of the pivots, the tolerance only gates the inversion.
+
+        for k in 0..N {
+            let p = (k..N)
+                .max_by(|i, j| a[*i][k].abs().total_cmp(&a[*j][k].abs()))
+                .unwrap_or(k);
+            if a[p][k] == 0.0 { return 0.0 }
+            if p != k {
+                a.swap(k, p);
+                det = -det;
+            }
+
+            det *= a[k][k];
+            let pivot_str = a[k];
+            for str in a.iter_mut().skip(k+1) {
+                let f = str[k] / pivot_str[k];
+                for (e, p) in str[k+1..].iter_mut().zip(&pivot_str[k+1..]) {
+                    *e -= f * p;
+                }
+            }
+        }
+        det
+    }
+
+    fn _inverse(&self) -> Option<Self> {
+        let mut a = self.body;
+        let mut inv = Self::identity().body;
+
+        // Pivots smaller than this are rounding noise of a singular matrix.
+        let tolerance = noise_level(N, a.iter().flatten());
+
+        for k in 0..N {
+            let p = (k..N)
+                .max_by(|i, j| a[*i][k].abs().total_cmp(&a[*j][k].abs()))
+                .unwrap_or(k);
+            if a[p][k].abs() <= tolerance { return None }
+            a.swap(k, p);
+            inv.swap(k, p);
+
+            let pivot = a[k][k];
+            for r in 0..N {
+                a[k][r] /= pivot;
+                inv[k][r] /= pivot;
+            }
+            for s in (0..N).filter(|s| *s != k) {
+                let f = a[s][k];
+                if f == 0.0 { continue }
+                for r in 0..N {
+                    a[s][r] -= f * a[k][r];
+                    inv[s][r] -= f * inv[k][r];
+                }
+            }
+        }
+        Some(Self { body: inv })
+    }
+}
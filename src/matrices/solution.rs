@@ -0,0 +1,75 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::{Real, Scalar};
+use crate::vector_algebra::Vector;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution<T: Scalar = f64> {
+    /// The only solution.
+    Unique(Vector<T>),
+    /// x = particular + basis * t for any vector t (free parameters).
+    Infinite { particular: Vector<T>, basis: Matrix<T> },
+    /// The system has no solutions.
+    Inconsistent,
+}
+
+impl<T: Real> Matrix<T> {
+    pub fn solve_general(&self, d: &[T]) -> Result<Solution<T>, MatrixError> {
+        //! General solution of A*x = d for any matrix (reduced row echelon form of [A | d]).
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::matrices::solution::Solution;
+        //!
+        //! // x1 + 2*x2 + x3 = 4
+        //! // 2*x1 + 4*x2 = 6
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 2.0, 1.0,
+        //!     2.0, 4.0, 0.0,
+        //! ]).unwrap();
+        //!
+        //! if let Solution::Infinite { particular, basis } = m.solve_general(&[4.0, 6.0]).unwrap() {
+        //!     println!("x = {} + {:?} * t", particular, basis.col(0));
+        //! }
+        //! // x = Vector (3) = [3, 0, 1] + [-2.0, 1.0, 0.0] * t
+        //! ```
+
+        Ok(match self._f64()._solve_general(&T::slice_to_f64(d))? {
+            Solution::Unique(x) => Solution::Unique(Vector::from(T::vec_from_f64(x.into_vec()))),
+            Solution::Infinite { particular, basis } => Solution::Infinite {
+                particular: Vector::from(T::vec_from_f64(particular.into_vec())),
+                basis: T::matrix_from_f64(basis),
+            },
+            Solution::Inconsistent => Solution::Inconsistent,
+        })
+    }
+}
+
+
+impl Matrix {
+    fn _solve_general(&self, d: &[f64]) -> Result<Solution, MatrixError> {
+        if d.len() != self.strings { return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (d.len(), 1) }) }
+
+        let n = self.rows;
+        let mut augmented = Vec::<f64>::with_capacity(self.strings * (n + 1));
+        for (str, e) in self.body.chunks(n).zip(d) {
+            augmented.extend_from_slice(str);
+            augmented.push(*e);
+        }
+        let (r, pivots) = Self::new(self.strings, n + 1, augmented)?.rref();
+
+        // Rouche-Capelli theorem: a pivot in the d row means rank(A) < rank([A | d]).
+        if pivots.last() == Some(&n) { return Ok(Solution::Inconsistent) }
+
+        let mut particular = vec![0.0; n];
+        for (s, p) in pivots.iter().enumerate() {
+            particular[*p] = r.body[s * (n + 1) + n]
+        }
+
+        Ok(match Self::_null_space(&r, &pivots, n) {
+            Some(basis) => Solution::Infinite { particular: Vector::from(particular), basis },
+            None => Solution::Unique(Vector::from(particular)),
+        })
+    }
+}
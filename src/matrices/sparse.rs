@@ -0,0 +1,220 @@
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::iterative::{self, IterativeSettings};
+use crate::vector_algebra::Vector;
+
+
+#[derive(Debug, Clone, Default)]
+pub struct CooBuilder {
+    strings: usize,
+    rows: usize,
+    entries: Vec<(usize, usize, f64)>,
+}
+impl CooBuilder {
+    pub fn new(strings: usize, rows: usize) -> Result<Self, MatrixError> {
+        //! Builder of the [SparseMatrix] in the coordinate (COO) format.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::sparse::CooBuilder;
+        //!
+        //! let mut b = CooBuilder::new(3, 3).unwrap();
+        //! b.push(0, 0, 2.0).unwrap();
+        //! b.push(1, 2, -1.0).unwrap();
+        //! b.push(1, 2, -1.0).unwrap();
+        //!
+        //! let m = b.build();
+        //!
+        //! println!("nnz = {}, m[1, 2] = {}", m.nnz(), m.get(1, 2));
+        //! // nnz = 2, m[1, 2] = -2
+        //! ```
+
+        if strings == 0 || rows == 0 { return Err(MatrixError::Empty) }
+
+        Ok(Self { strings, rows, entries: Vec::new() })
+    }
+
+    pub fn push(&mut self, s: usize, r: usize, value: f64) -> Result<(), MatrixError> {
+        //! Adds the value to the element (s, r). Values of the same element are summed up.
+
+        if s >= self.strings || r >= self.rows {
+            return Err(MatrixError::IndexOutOfBounds { index: (s, r), shape: (self.strings, self.rows) })
+        }
+
+        self.entries.push((s, r, value));
+        Ok(())
+    }
+
+    pub fn build(mut self) -> SparseMatrix {
+        self.entries.sort_by_key(|(s, r, _)| (*s, *r));
+
+        let mut values = Vec::<f64>::new();
+        let mut indices = Vec::<usize>::new();
+        let mut indptr = vec![0; self.strings + 1];
+
+        let mut last: Option<(usize, usize)> = None;
+        for (s, r, v) in self.entries {
+            if last == Some((s, r)) {
+                *values.last_mut().unwrap() += v;
+                continue
+            }
+            values.push(v);
+            indices.push(r);
+            indptr[s + 1] += 1;
+            last = Some((s, r));
+        }
+        for s in 0..self.strings {
+            indptr[s + 1] += indptr[s];
+        }
+
+        SparseMatrix { strings: self.strings, rows: self.rows, values, indices, indptr }.pruned()
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseMatrix {
+    strings: usize,
+    rows: usize,
+    // CSR: the values of the s-th string are values[indptr[s]..indptr[s+1]],
+    // indices holds their row numbers.
+    values: Vec<f64>,
+    indices: Vec<usize>,
+    indptr: Vec<usize>,
+}
+impl From<&Matrix> for SparseMatrix {
+    fn from(m: &Matrix) -> Self {
+        let mut values = Vec::<f64>::new();
+        let mut indices = Vec::<usize>::new();
+        let mut indptr = vec![0];
+
+        for s in 0..m.strings {
+            for r in 0..m.rows {
+                let e = m.body[s * m.rows + r];
+                if e != 0.0 {
+                    values.push(e);
+                    indices.push(r);
+                }
+            }
+            indptr.push(values.len());
+        }
+        Self { strings: m.strings, rows: m.rows, values, indices, indptr }
+    }
+}
+impl From<&SparseMatrix> for Matrix {
+    fn from(m: &SparseMatrix) -> Self {
+        let mut body = vec![0.0; m.strings * m.rows];
+        for s in 0..m.strings {
+            for i in m.indptr[s]..m.indptr[s + 1] {
+                body[s * m.rows + m.indices[i]] = m.values[i]
+            }
+        }
+        Matrix::new(m.strings, m.rows, body).unwrap()
+    }
+}
+impl SparseMatrix {
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn nnz(&self) -> usize {
+        //! The number of stored (non-zero) elements.
+
+        self.values.len()
+    }
+
+    pub fn get(&self, s: usize, r: usize) -> f64 {
+        if s >= self.strings { return 0.0 }
+
+        let str = self.indptr[s]..self.indptr[s + 1];
+        match self.indices[str.clone()].binary_search(&r) {
+            Ok(i) => self.values[str.start + i],
+            Err(_) => 0.0,
+        }
+    }
+
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vector, MatrixError> {
+        //! Sparse matrix-vector product.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::matrices::sparse::SparseMatrix;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 0.0, 2.0,
+        //!     0.0, 3.0, 0.0,
+        //! ]).unwrap();
+        //!
+        //! let sm = SparseMatrix::from(&m);
+        //!
+        //! println!("m * x = {}", sm.mul_vec(&[1.0, 1.0, 1.0]).unwrap());
+        //! // m * x = Vector (2) = [3, 3]
+        //! ```
+
+        if x.len() != self.rows { return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (x.len(), 1) }) }
+
+        Ok(
+            (0..self.strings)
+                .map(|s| self.string(s).map(|(r, e)| e * x[r]).sum())
+                .collect()
+        )
+    }
+
+    pub fn cg(&self, d: &[f64], tolerance: f64, max_iter: usize) -> Result<Option<Vector>, MatrixError> {
+        //! Solves A*x = d for a symmetric positive definite matrix (conjugate gradient method).
+        //! Stops when |A*x - d| <= tolerance * |d|, returns None if max_iter is reached first.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::sparse::CooBuilder;
+        //!
+        //! // -x[i-1] + 2*x[i] - x[i+1] = 1
+        //! let n = 100;
+        //! let mut b = CooBuilder::new(n, n).unwrap();
+        //! for i in 0..n {
+        //!     b.push(i, i, 2.0).unwrap();
+        //!     if i > 0 { b.push(i, i - 1, -1.0).unwrap() }
+        //!     if i < n - 1 { b.push(i, i + 1, -1.0).unwrap() }
+        //! }
+        //! let m = b.build();
+        //!
+        //! let x = m.cg(&vec![1.0; n], 1e-10, 1000).unwrap().unwrap();
+        //!
+        //! println!("x[0] = {:.4}, x[49] = {:.4}", x[0], x[49]);
+        //! // x[0] = 50.0000, x[49] = 1275.0000
+        //! ```
+
+        let settings = IterativeSettings { tolerance, max_iter, x0: None };
+        let (x, report) = iterative::cg(self, d, &settings)?;
+
+        Ok(if report.converged { Some(x) } else { None })
+    }
+
+    pub(crate) fn string(&self, s: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        (self.indptr[s]..self.indptr[s + 1]).map(|i| (self.indices[i], self.values[i]))
+    }
+
+    fn pruned(mut self) -> Self {
+        // Removes explicit zeros, e.g. after the duplicates have cancelled each other out.
+        if self.values.iter().all(|e| *e != 0.0) { return self }
+
+        let mut values = Vec::<f64>::new();
+        let mut indices = Vec::<usize>::new();
+        let mut indptr = vec![0];
+        for s in 0..self.strings {
+            for i in self.indptr[s]..self.indptr[s + 1] {
+                if self.values[i] != 0.0 {
+                    values.push(self.values[i]);
+                    indices.push(self.indices[i]);
+                }
+            }
+            indptr.push(values.len());
+        }
+        self.values = values;
+        self.indices = indices;
+        self.indptr = indptr;
+        self
+    }
+}
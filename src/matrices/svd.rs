@@ -0,0 +1,221 @@
+use crate::matrices::{noise_level, Matrix};
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Real;
+
+
+impl<T: Real> Matrix<T> {
+    pub fn svd(&self) -> Result<(Self, Vec<T>, Self), MatrixError> {
+        //! Singular value decomposition (one-sided Jacobi method): A = U * S * V^T.
+        //!
+        //! U - matrix (strings x k) with orthonormal columns
+        //!
+        //! S - singular values in descending order (the diagonal of the k x k matrix S)
+        //!
+        //! V^T - matrix (k x rows) with orthonormal strings
+        //!
+        //! k - the smaller of the matrix dimensions
+        //!
+        //! NotConverged is returned if the columns are not orthogonal after 60 sweeps.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     3.0, 0.0,
+        //!     4.0, 5.0,
+        //! ]).unwrap();
+        //!
+        //! let (u, s, vt) = m.svd().unwrap();
+        //!
+        //! println!("S = {:.4?}", s);
+        //! // S = [6.7082, 2.2361]
+        //! ```
+
+        let (u, s, vt) = self._f64()._svd()?;
+        Ok((T::matrix_from_f64(u), T::vec_from_f64(s), T::matrix_from_f64(vt)))
+    }
+
+    pub fn rank(&self, tolerance: f64) -> Result<usize, MatrixError> {
+        //! Matrix rank, the number of singular values greater than the tolerance.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 2, vec![
+        //!     1.0, 2.0,
+        //!     2.0, 4.0,
+        //!     3.0, 6.0,
+        //! ]).unwrap();
+        //!
+        //! println!("rank = {}", m.rank(1e-10).unwrap());
+        //! // rank = 1
+        //! ```
+
+        self._f64()._rank(tolerance)
+    }
+
+    pub fn pinv(&self) -> Result<Self, MatrixError> {
+        //! Moore-Penrose pseudo-inverse, A+ = V * S+ * U^T.
+        //! Singular values that are rounding noise are treated as zeros.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 2.0,
+        //!     2.0, 4.0,
+        //! ]).unwrap();
+        //!
+        //! println!("pinv = {:.4?}", m.pinv().unwrap());
+        //! // pinv = Matrix { strings: 2, rows: 2, body: [0.0400, 0.0800, 0.0800, 0.1600] }
+        //! ```
+
+        Ok(T::matrix_from_f64(self._f64()._pinv()?))
+    }
+
+    pub fn cond(&self) -> Result<T, MatrixError> {
+        //! Condition number in the spectral norm, the largest singular value divided by the smallest.
+        //! Returns 'inf' for singular matrices.
+        //! # Example
+        //! ```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(2, 2, vec![
+        //!     1.0, 1.0,
+        //!     1.0, 1.0001,
+        //! ]).unwrap();
+        //!
+        //! println!("cond = {:.0}", m.cond().unwrap());
+        //! // cond = 40002
+        //! ```
+
+        Ok(T::from_f64(self._f64()._cond()?))
+    }
+}
+
+
+impl Matrix {
+    fn _svd(&self) -> Result<(Self, Vec<f64>, Self), MatrixError> {
+        if self.strings < self.rows {
+            let (u, s, vt) = self.transpose()._svd()?;
+            return Ok((vt.transpose(), s, u.transpose()))
+        }
+
+        let (m, n) = (self.strings, self.rows);
+        let mut a = self.body.to_vec();
+        let mut v = vec![0.0; n * n];
+        for i in 0..n { v[i * n + i] = 1.0 }
+
+        let mut is_converged = false;
+        for _ in 0..60 {
+            is_converged = true;
+
+            for p in 0..n {
+                for q in p+1..n {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for i in 0..m {
+                        alpha += a[i * n + p] * a[i * n + p];
+                        beta += a[i * n + q] * a[i * n + q];
+                        gamma += a[i * n + p] * a[i * n + q];
+                    }
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 { continue }
+                    is_converged = false;
+
+                    // rotation which makes the columns p and q orthogonal
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for i in 0..m {
+                        let (aip, aiq) = (a[i * n + p], a[i * n + q]);
+                        a[i * n + p] = c * aip - s * aiq;
+                        a[i * n + q] = s * aip + c * aiq;
+                    }
+                    for i in 0..n {
+                        let (vip, viq) = (v[i * n + p], v[i * n + q]);
+                        v[i * n + p] = c * vip - s * viq;
+                        v[i * n + q] = s * vip + c * viq;
+                    }
+                }
+            }
+            if is_converged { break }
+        }
+        if !is_converged { return Err(MatrixError::NotConverged) }
+
+        let norms = (0..n)
+            .map(|r| (0..m).map(|i| a[i * n + r] * a[i * n + r]).sum::<f64>().sqrt())
+            .collect::<Vec<f64>>();
+        let mut order = (0..n).collect::<Vec<usize>>();
+        order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+
+        let tolerance = noise_level(m, &norms);
+
+        let mut s = Vec::<f64>::new();
+        let mut u = vec![0.0; m * n];
+        let mut vt = vec![0.0; n * n];
+        for (c, r) in order.iter().enumerate() {
+            for i in 0..n {
+                vt[c * n + i] = v[i * n + r]
+            }
+            if norms[*r] > tolerance {
+                for i in 0..m {
+                    u[i * n + c] = a[i * n + r] / norms[*r]
+                }
+                s.push(norms[*r]);
+            } else {
+                s.push(0.0);
+            }
+        }
+
+        // U columns of zero singular values complete the orthonormal basis.
+        for c in s.iter().position(|e| *e == 0.0).unwrap_or(n)..n {
+            for k in 0..m {
+                let mut e = vec![0.0; m];
+                e[k] = 1.0;
+                for j in 0..c {
+                    let dot: f64 = (0..m).map(|i| u[i * n + j] * e[i]).sum();
+                    for i in 0..m { e[i] -= dot * u[i * n + j] }
+                }
+                let norm = e.iter().map(|x| x * x).sum::<f64>().sqrt();
+                if norm > 0.5 {
+                    for i in 0..m { u[i * n + c] = e[i] / norm }
+                    break
+                }
+            }
+        }
+
+        Ok((Self::new(m, n, u)?, s, Self::new(n, n, vt)?))
+    }
+
+    fn _rank(&self, tolerance: f64) -> Result<usize, MatrixError> {
+        let (_, s, _) = self._svd()?;
+        Ok(s.iter().filter(|e| **e > tolerance).count())
+    }
+
+    fn _pinv(&self) -> Result<Self, MatrixError> {
+        let (u, s, vt) = self._svd()?;
+        let k = s.len();
+        let tolerance = noise_level(self.strings.max(self.rows), &s);
+
+        let mut body = vec![0.0; self.rows * self.strings];
+        for r in 0..self.rows {
+            for c in 0..self.strings {
+                body[r * self.strings + c] = (0..k)
+                    .filter(|i| s[*i] > tolerance)
+                    .map(|i| vt.body[i * self.rows + r] / s[i] * u.body[c * k + i])
+                    .sum();
+            }
+        }
+
+        Self::new(self.rows, self.strings, body)
+    }
+
+    fn _cond(&self) -> Result<f64, MatrixError> {
+        let (_, s, _) = self._svd()?;
+        Ok(match s.last() {
+            Some(min) if *min > 0.0 => s[0] / min,
+            _ => f64::INFINITY,
+        })
+    }
+}
@@ -0,0 +1,187 @@
+use std::ops::{Index, IndexMut};
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Scalar;
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T = f64> {
+    strings: usize,
+    rows: usize,
+    // The distance between the strings in the body (the number of rows of the parent matrix).
+    stride: usize,
+    body: &'a [T],
+}
+
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T = f64> {
+    strings: usize,
+    rows: usize,
+    stride: usize,
+    body: &'a mut [T],
+}
+
+
+fn view_range(m_strings: usize, m_rows: usize, s: usize, r: usize, strings: usize, rows: usize)
+    -> Result<std::ops::Range<usize>, MatrixError>
+{
+    if strings == 0 || rows == 0 { return Err(MatrixError::Empty) }
+    if s + strings > m_strings || r + rows > m_rows {
+        return Err(MatrixError::IndexOutOfBounds { index: (s + strings - 1, r + rows - 1), shape: (m_strings, m_rows) })
+    }
+
+    Ok(s * m_rows + r..(s + strings - 1) * m_rows + r + rows)
+}
+
+impl<T: Scalar> Matrix<T> {
+    pub fn view(&self, s: usize, r: usize, strings: usize, rows: usize) -> Result<MatrixView<'_, T>, MatrixError> {
+        //! Borrowed submatrix (strings x rows) starting at the element (s, r).
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let m = Matrix::new(3, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //!     7.0, 8.0, 9.0,
+        //! ]).unwrap();
+        //!
+        //! let v = m.view(1, 1, 2, 2).unwrap();
+        //!
+        //! println!("v[0, 1] = {}", v[(0, 1)]);
+        //! // v[0, 1] = 6
+        //! println!("v = {:?}", v.to_matrix());
+        //! // v = Matrix { strings: 2, rows: 2, body: [5.0, 6.0, 8.0, 9.0] }
+        //! ```
+
+        let range = view_range(self.strings, self.rows, s, r, strings, rows)?;
+        Ok(MatrixView { strings, rows, stride: self.rows, body: &self.body[range] })
+    }
+
+    pub fn view_mut(&mut self, s: usize, r: usize, strings: usize, rows: usize) -> Result<MatrixViewMut<'_, T>, MatrixError> {
+        //! Mutable borrowed submatrix (strings x rows) starting at the element (s, r).
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //!
+        //! let mut m = Matrix::new(3, 3, vec![0.0; 9]).unwrap();
+        //!
+        //! let mut v = m.view_mut(0, 1, 2, 2).unwrap();
+        //! v[(1, 1)] = 5.0;
+        //! v.fill(1.0);
+        //!
+        //! println!("m = {:?}", m);
+        //! // m = Matrix { strings: 3, rows: 3, body: [0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0] }
+        //! ```
+
+        let range = view_range(self.strings, self.rows, s, r, strings, rows)?;
+        Ok(MatrixViewMut { strings, rows, stride: self.rows, body: &mut self.body[range] })
+    }
+}
+
+
+impl<T: Scalar> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (s, r): (usize, usize)) -> &Self::Output {
+        self.get(s, r).expect("The index is out of the matrix.")
+    }
+}
+impl<T: Scalar> MatrixView<'_, T> {
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get(&self, s: usize, r: usize) -> Option<&T> {
+        if s >= self.strings || r >= self.rows { return None }
+
+        self.body.get(s * self.stride + r)
+    }
+
+    pub fn row(&self, s: usize) -> &[T] {
+        //! The s-th string of the view.
+
+        &self.body[s * self.stride..s * self.stride + self.rows]
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        //! Copies the view into a new matrix.
+
+        let body = (0..self.strings)
+            .flat_map(|s| self.row(s).iter().cloned())
+            .collect();
+        Matrix::new(self.strings, self.rows, body).unwrap()
+    }
+}
+
+
+impl<T: Scalar> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (s, r): (usize, usize)) -> &Self::Output {
+        self.get(s, r).expect("The index is out of the matrix.")
+    }
+}
+impl<T: Scalar> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (s, r): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(s, r).expect("The index is out of the matrix.")
+    }
+}
+impl<T: Scalar> MatrixViewMut<'_, T> {
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get(&self, s: usize, r: usize) -> Option<&T> {
+        if s >= self.strings || r >= self.rows { return None }
+
+        self.body.get(s * self.stride + r)
+    }
+
+    pub fn get_mut(&mut self, s: usize, r: usize) -> Option<&mut T> {
+        if s >= self.strings || r >= self.rows { return None }
+
+        self.body.get_mut(s * self.stride + r)
+    }
+
+    pub fn row(&self, s: usize) -> &[T] {
+        &self.body[s * self.stride..s * self.stride + self.rows]
+    }
+
+    pub fn row_mut(&mut self, s: usize) -> &mut [T] {
+        &mut self.body[s * self.stride..s * self.stride + self.rows]
+    }
+
+    pub fn fill(&mut self, value: T) {
+        for s in 0..self.strings {
+            self.row_mut(s).fill(value.clone())
+        }
+    }
+
+    pub fn copy_from(&mut self, m: &Matrix<T>) -> Result<(), MatrixError> {
+        //! Copies the matrix of the same size into the view.
+
+        if m.strings != self.strings || m.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch { left: (self.strings, self.rows), right: (m.strings, m.rows) })
+        }
+        for (s, str) in m.body.chunks(m.rows).enumerate() {
+            self.row_mut(s).clone_from_slice(str)
+        }
+        Ok(())
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let body = (0..self.strings)
+            .flat_map(|s| self.row(s).iter().cloned())
+            .collect();
+        Matrix::new(self.strings, self.rows, body).unwrap()
+    }
+}
@@ -8,3 +8,4 @@ pub(crate) mod test_top;
 pub(crate) mod test_common;
 pub(crate) mod test_func_analysis;
 pub(crate) mod test_utils;
+pub(crate) mod test_serde;
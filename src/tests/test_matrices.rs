@@ -1,6 +1,17 @@
 #[cfg(test)]
 mod test_matrices {
+    use crate::im::cast::ImValue;
+    use crate::im::im_matrices::ImMatrix;
     use crate::matrices::Matrix;
+    use crate::matrices::smatrix::SMatrix;
+    use crate::matrices::solution::Solution;
+    use crate::matrices::io::{MarketFormat, ParseError, ReadError};
+    use crate::matrices::error::MatrixError;
+    use crate::matrices::sparse::{CooBuilder, SparseMatrix};
+    use crate::matrices::iterative::{cg, gauss_seidel, gmres, jacobi, sor, IterativeSettings};
+    use crate::utils::AdvancedEQ;
+    use crate::utils::default::PRECISION;
+    use crate::vector_algebra::Vector;
 
     #[test]
     fn test_matrices_det1_pos() {
@@ -10,7 +21,7 @@ mod test_matrices {
             3.0, -2.0, -1.0,
         ]).unwrap();
         let d = m.det();
-        assert_eq!(d, 1.0);
+        assert!(d.is_equal(1.0, PRECISION));
     }
 
     #[test]
@@ -20,7 +31,7 @@ mod test_matrices {
             -130.0, -230.0,
         ]).unwrap();
         let d = m.det();
-        assert_eq!(d, -100.0);
+        assert!(d.is_equal(-100.0, PRECISION));
     }
 
     #[test]
@@ -53,7 +64,63 @@ mod test_matrices {
             0.0, 1.0, 4.0, 4.0, 7.0,
         ]).unwrap();
         let d = m.det();
-        assert_eq!(d, 156.0);
+        assert!(d.is_equal(156.0, PRECISION));
+    }
+
+    #[test]
+    fn test_matrices_det6_pos() {
+        let m = Matrix::new(1,1,vec![-4.5]).unwrap();
+        assert_eq!(m.det(), -4.5);
+    }
+
+    #[test]
+    fn test_matrices_det7_pos() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        ]).unwrap();
+        assert!(m.det().is_equal(0.0, PRECISION));
+        assert!(m.inverse().is_none());
+        assert!(m.slae(&[1.0, 2.0, 3.0]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_matrices_det8_pos() {
+        // Tiny pivots are not rounded to zero in the determinant.
+        let m = Matrix::from_diagonal(&[1e-20, 1e20]).unwrap();
+        assert!(m.det().is_equal(1.0, 1e-12));
+        let m = Matrix::from_diagonal(&[1e-100, 1e-100]).unwrap();
+        assert!(m.det().is_equal(1e-200, 1e-210));
+    }
+
+    #[test]
+    fn test_matrices_lu1_pos() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+            1.0, 5.0, 2.0,
+        ]).unwrap();
+        let (l, u, p) = m.lu().unwrap();
+        let pm = Matrix::new(3,3, p
+            .iter()
+            .flat_map(|s| m.body[s * 3..s * 3 + 3].to_vec())
+            .collect()
+        ).unwrap();
+        assert_eq!(p, vec![1, 2, 0]);
+        assert_eq!(l * u, pm);
+    }
+
+    #[test]
+    fn test_matrices_lu2_pos() {
+        let m = Matrix::new(2,2,vec![
+            0.0, 2.0,
+            3.0, 1.0,
+        ]).unwrap();
+        let (l, u, p) = m.lu().unwrap();
+        assert_eq!(l, Matrix::new(2,2,vec![1.0, 0.0, 0.0, 1.0]).unwrap());
+        assert_eq!(u, Matrix::new(2,2,vec![3.0, 1.0, 0.0, 2.0]).unwrap());
+        assert_eq!(p, vec![1, 0]);
     }
 
     #[test]
@@ -116,7 +183,8 @@ mod test_matrices {
             5.0,
             11.0,
         ]).unwrap();
-        assert_eq!(m1 * m2, test_res);
+        assert!(m1.checked_mul(&m2).is_err());
+        assert_eq!(m2 * m1, test_res);
     }
 
     #[test]
@@ -139,6 +207,37 @@ mod test_matrices {
         assert_eq!(m1 * m2, test_res);
     }
 
+    #[test]
+    fn test_matrices_mul6_pos() {
+        let m1 = Matrix::new(1,2,vec![
+            1.0, 2.0,
+        ]).unwrap();
+        let m2 = Matrix::new(2,2,vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        let test_res = Matrix::new(1,2, vec![
+            7.0, 10.0,
+        ]).unwrap();
+        assert_eq!(m1 * m2, test_res);
+    }
+
+    #[test]
+    fn test_matrices_mul7_pos() {
+        // Big enough for the multithreaded kernel.
+        let (n, k, m) = (70, 130, 90);
+        let a = Matrix::new(n, k, (0..n * k).map(|i| (i % 13) as f64 - 6.0).collect()).unwrap();
+        let b = Matrix::new(k, m, (0..k * m).map(|i| (i % 7) as f64 * 0.5).collect()).unwrap();
+
+        let mut body = vec![0.0; n * m];
+        for s in 0..n {
+            for r in 0..m {
+                body[s * m + r] = (0..k).map(|i| a.body[s * k + i] * b.body[i * m + r]).sum();
+            }
+        }
+        assert_eq!(a.mul_by_ref(&b).unwrap(), Matrix::new(n, m, body).unwrap());
+    }
+
     #[test]
     fn test_matrices_mul_num1_pos() {
         let m = Matrix::new(3,3,vec![
@@ -223,6 +322,20 @@ mod test_matrices {
         assert_eq!(m.transpose(), test_res);
     }
 
+    #[test]
+    fn test_matrices_trans_3_pos() {
+        let m = Matrix::new(3,2,vec![
+            1.0, 3.0,
+            1.0, -4.0,
+            2.0, 5.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2,3,vec![
+            1.0, 1.0, 2.0,
+            3.0, -4.0, 5.0,
+        ]).unwrap();
+        assert_eq!(m.transpose(), test_res);
+    }
+
     #[test]
     fn test_matrices_inverse1_pos() {
         let m = Matrix::new(3,3,vec![
@@ -238,6 +351,13 @@ mod test_matrices {
         assert_eq!(m.inverse().unwrap(), test_res);
     }
 
+    #[test]
+    fn test_matrices_inverse2_pos() {
+        let m = Matrix::new(1,1,vec![4.0]).unwrap();
+        let test_res = Matrix::new(1,1,vec![0.25]).unwrap();
+        assert_eq!(m.inverse().unwrap(), test_res);
+    }
+
     #[test]
     fn test_matrices_cfm1_pos() {
         let m = Matrix::new(3,3,vec![
@@ -266,6 +386,13 @@ mod test_matrices {
         assert_eq!(m.cofactor_matrix().unwrap(), test_res);
     }
 
+    #[test]
+    fn test_matrices_cfm3_pos() {
+        let m = Matrix::new(1, 1, vec![5.0]).unwrap();
+        assert_eq!(m.cofactor_matrix().unwrap(), Matrix::new(1, 1, vec![1.0]).unwrap());
+        assert_eq!(m.inverse().unwrap(), Matrix::new(1, 1, vec![0.2]).unwrap());
+    }
+
     #[test]
     fn test_matrices_pow1_pos() {
         let m = Matrix::new(3,3,vec![
@@ -306,7 +433,7 @@ mod test_matrices {
             1.0, 5.0, 2.0,
         ]).unwrap();
         let d = &[1.0, 3.0, 2.0];
-        let test_res = vec![2.0, 1.0, -2.5];
+        let test_res = Vector::new(vec![2.0, 1.0, -2.5]);
         assert_eq!(m.slae(d).unwrap().unwrap(), test_res);
     }
 
@@ -321,187 +448,1803 @@ mod test_matrices {
     }
 
     #[test]
-    fn test_matrices1_neg() {
-        let m = Matrix::new(3,3,vec![
-            131.0, 231.0, 5.4,
-            0.0, -0.0, 4.5,
-        ]);
-        assert!(m.is_err());
+    fn test_matrices_slae3_pos() {
+        let m = Matrix::new(1,1,vec![2.0]).unwrap();
+        assert_eq!(m.slae(&[3.0]).unwrap().unwrap(), Vector::new(vec![1.5]));
     }
 
     #[test]
-    fn test_matrices2_neg() {
-        let m = Matrix::new(2,2,vec![
-            131.0, 231.0, 5.4,
-            0.0, -0.0, 4.5,
-        ]);
-        assert!(m.is_err());
+    fn test_matrices_slae4_pos() {
+        let n = 10;
+        let mut body = vec![1.0; n * n];
+        for i in 0..n { body[i * n + i] = n as f64 + 1.0 }
+        let m = Matrix::new(n, n, body).unwrap();
+
+        // every x = 1.0, so every d = 2n
+        let d = vec![2.0 * n as f64; n];
+        let res = m.slae(&d).unwrap().unwrap();
+        assert!(res.iter().all(|x| x.is_equal(1.0, PRECISION)));
     }
 
     #[test]
-    fn test_matrices3_neg() {
-        let m = Matrix::new(2,2,vec![]);
-        assert!(m.is_err());
+    fn test_matrices_qr1_pos() {
+        let m = Matrix::new(3,2,vec![
+            3.0, 1.0,
+            4.0, 2.0,
+            0.0, 2.0,
+        ]).unwrap();
+        let (q, r) = m.qr().unwrap();
+        let e = Matrix::new(2,2,vec![1.0, 0.0, 0.0, 1.0]).unwrap();
+        assert_eq!(q.transpose() * q.clone(), e);
+        assert_eq!(r.body[2], 0.0);
+        assert_eq!(q * r, m);
     }
 
     #[test]
-    fn test_matrices4_neg() {
-        let m = Matrix::new(2,0,vec![
-            131.0, 231.0, 5.4,
-            0.0, -0.0, 4.5,
-        ]);
-        assert!(m.is_err());
+    fn test_matrices_qr2_pos() {
+        let m = Matrix::new(3,3,vec![
+            12.0, -51.0, 4.0,
+            6.0, 167.0, -68.0,
+            -4.0, 24.0, -41.0,
+        ]).unwrap();
+        let (q, r) = m.qr().unwrap();
+        let test_res = Matrix::new(3,3,vec![
+            -14.0, -21.0, 14.0,
+            0.0, -175.0, 70.0,
+            0.0, 0.0, 35.0,
+        ]).unwrap();
+        assert_eq!(r, test_res);
+        assert_eq!(q * r, m);
     }
 
     #[test]
-    fn test_matrices5_neg() {
-        let m = Matrix::new(0,2,vec![
-            131.0, 231.0, 5.4,
-            0.0, -0.0, 4.5,
-        ]);
-        assert!(m.is_err());
+    fn test_matrices_lstsq1_pos() {
+        let m = Matrix::new(3,2,vec![
+            1.0, 0.0,
+            1.0, 1.0,
+            1.0, 2.0,
+        ]).unwrap();
+        let (x, residual) = m.lstsq(&[1.0, 3.0, 4.0]).unwrap().unwrap();
+        assert!(x[0].is_equal(7.0 / 6.0, PRECISION));
+        assert!(x[1].is_equal(1.5, PRECISION));
+        assert!(residual.is_equal((1.0_f64 / 6.0).sqrt(), PRECISION));
     }
 
     #[test]
-    fn test_matrices_mul1_neg() {
-        let m1 = Matrix::new(2,1,vec![
-            1.0,
-            2.0,
+    fn test_matrices_lstsq2_pos() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+            1.0, 5.0, 2.0,
         ]).unwrap();
-        let m2 = Matrix::new(3,2,vec![
-            1.0, 2.0,
-            3.0, 4.0,
+        let (x, residual) = m.lstsq(&[1.0, 3.0, 2.0]).unwrap().unwrap();
+        assert!(x.iter().zip([2.0, 1.0, -2.5]).all(|(a, b)| a.is_equal(b, PRECISION)));
+        assert!(residual.is_equal(0.0, PRECISION));
+    }
+
+    #[test]
+    fn test_matrices_eigen_sym1_pos() {
+        let m = Matrix::new(2,2,vec![
+            2.0, 1.0,
             1.0, 2.0,
         ]).unwrap();
-        let test_res = Matrix::new(2,1, vec![
-            5.0,
-            11.0,
+        let (values, vectors) = m.eigen_sym().unwrap();
+        let h = 0.5_f64.sqrt();
+        let test_res = Matrix::new(2,2,vec![
+            h, h,
+            -h, h,
         ]).unwrap();
-        assert_ne!(m1 * m2, test_res);
+        assert!(values[0].is_equal(1.0, PRECISION));
+        assert!(values[1].is_equal(3.0, PRECISION));
+        assert_eq!(vectors, test_res);
     }
 
     #[test]
-    fn test_matrices_mul2_neg() {
-        let m1 = Matrix::new(3,2,vec![
-            1.0, 2.0,
-            3.0, 4.0,
-            1.0, 2.0,
+    fn test_matrices_eigen_sym2_pos() {
+        let m = Matrix::new(3,3,vec![
+            2.0, -1.0, 0.0,
+            -1.0, 2.0, -1.0,
+            0.0, -1.0, 2.0,
         ]).unwrap();
-        let m2 = Matrix::new(2,1,vec![
-            1.0,
-            2.0,
+        let (values, vectors) = m.eigen_sym().unwrap();
+        let s = 2.0_f64.sqrt();
+        assert!(values.iter().zip([2.0 - s, 2.0, 2.0 + s]).all(|(a, b)| a.is_equal(b, PRECISION)));
+
+        // A*V = V*diag(values)
+        let d = Matrix::new(3,3,vec![
+            values[0], 0.0, 0.0,
+            0.0, values[1], 0.0,
+            0.0, 0.0, values[2],
         ]).unwrap();
-        let test_res = Matrix::new(2,1, vec![
-            5.0,
-            11.0,
+        assert_eq!(m * vectors.clone(), vectors.clone() * d);
+        let e = Matrix::new(3,3,vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+        assert_eq!(vectors.transpose() * vectors, e);
+    }
+
+    #[test]
+    fn test_matrices_eigen_sym3_pos() {
+        let m = Matrix::new(1,1,vec![-3.0]).unwrap();
+        let (values, vectors) = m.eigen_sym().unwrap();
+        assert_eq!(values, vec![-3.0]);
+        assert_eq!(vectors, Matrix::new(1,1,vec![1.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_eigen_sym4_pos() {
+        // Large elements, symmetric up to rounding.
+        let m = Matrix::new(2,2,vec![
+            2e10, 1e10,
+            1e10 + 1e-3, 2e10,
         ]).unwrap();
-        assert_ne!(m1 * m2, test_res);
+        assert!(!m.is_symmetric(PRECISION));
+        let (values, _) = m.eigen_sym().unwrap();
+        assert!(values[0].is_equal(1e10, 1e-2));
+        assert!(values[1].is_equal(3e10, 1e-2));
+
+        // A^T*A of a larger matrix.
+        let a = Matrix::from_fn(30, 30, |s, r| ((s * 31 + r * 17) % 13) as f64 - 6.0).unwrap();
+        let ata = a.transpose().mul_by_ref(&a).unwrap();
+        let (values, vectors) = ata.eigen_sym().unwrap();
+        let d = Matrix::from_diagonal(&values).unwrap();
+        let res = vectors.mul_by_ref(&d).unwrap().mul_by_ref(&vectors.transpose()).unwrap();
+        assert!(res.body.iter().zip(&ata.body).all(|(x, y)| x.is_equal(*y, 1e-9 * ata.norm_fro())));
     }
 
     #[test]
-    fn test_matrices_add1_neg() {
-        let m1 = Matrix::new(3,3,vec![
-            1.0, 2.0, 3.0,
-            4.0, 5.0, 6.0,
-            7.0, 8.0, 9.0,
+    fn test_matrices_eigenvalues1_pos() {
+        let m = Matrix::new(2,2,vec![
+            1.0, -2.0,
+            2.0, 1.0,
         ]).unwrap();
-        let m2 = Matrix::new(3,3,vec![
-            1.0, 1.0, 2.0,
-            3.0, -4.0, 5.0,
-            6.0, 7.0, 8.0,
+        let test_res = vec![1.r() + (-2).i(), 1.r() + 2.i()];
+        assert_eq!(m.eigenvalues().unwrap(), test_res);
+    }
+
+    #[test]
+    fn test_matrices_eigenvalues2_pos() {
+        let m = Matrix::new(2,2,vec![
+            0.0, -1.0,
+            1.0, 0.0,
         ]).unwrap();
-        let test_res = Matrix::new(3,3,vec![
-            1.0, 3.0, 5.0,
-            7.0, 1.0, 11.0,
-            13.0, 15.0, 17.0,
+        assert_eq!(m.eigenvalues().unwrap(), vec![(-1).i(), 1.i()]);
+    }
+
+    #[test]
+    fn test_matrices_eigenvalues3_pos() {
+        // companion matrix of (x-1)(x-2)(x-3)(x-4)
+        let m = Matrix::new(4,4,vec![
+            10.0, -35.0, 50.0, -24.0,
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
         ]).unwrap();
-        assert_ne!(m1 + m2, test_res);
+        let values = m.eigenvalues().unwrap();
+        assert!(values.iter().all(|e| e.im_pow == 0.0));
+        assert!(values.iter().zip([1.0, 2.0, 3.0, 4.0]).all(|(a, b)| a.real.is_equal(b, PRECISION)));
     }
 
     #[test]
-    fn test_matrices_sub1_neg() {
-        let m1 = Matrix::new(3,3,vec![
+    fn test_matrices_eigen1_pos() {
+        let m = Matrix::new(3,3,vec![
+            2.0, 0.0, 0.0,
+            0.0, 3.0, 4.0,
+            0.0, 4.0, 9.0,
+        ]).unwrap();
+        let (values, vectors) = m.eigen().unwrap();
+        assert!(values.iter().zip([1.0, 2.0, 11.0]).all(|(a, b)| a.real.is_equal(b, PRECISION)));
+
+        // A*v = l*v
+        for (c, l) in values.iter().enumerate() {
+            for s in 0..3 {
+                let av: f64 = (0..3).map(|k| m.body[s * 3 + k] * vectors.body[k * 3 + c].real).sum();
+                assert!(av.is_equal(l.real * vectors.body[s * 3 + c].real, PRECISION));
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrices_eigen2_pos() {
+        let m = Matrix::new(2,2,vec![
+            0.0, -1.0,
+            1.0, 0.0,
+        ]).unwrap();
+        let (_, vectors) = m.eigen().unwrap();
+        let h = 0.5_f64.sqrt();
+
+        // v1 = (h, h*i) for -i, v2 = (h, -h*i) for i
+        assert!(vectors.body[0].real.is_equal(h, PRECISION) && vectors.body[0].im_pow == 0.0);
+        assert!(vectors.body[2].real.is_equal(h, PRECISION) && vectors.body[2].im_pow == 1.0);
+        assert!(vectors.body[1].real.is_equal(h, PRECISION) && vectors.body[1].im_pow == 0.0);
+        assert!(vectors.body[3].real.is_equal(-h, PRECISION) && vectors.body[3].im_pow == 1.0);
+    }
+
+    #[test]
+    fn test_matrices_eigen3_pos() {
+        let m = Matrix::new(3,3,vec![
+            2.0, 0.0, 1.0,
+            0.0, 2.0, 0.0,
+            0.0, 0.0, 3.0,
+        ]).unwrap();
+        let (values, vectors) = m.eigen().unwrap();
+        assert!(values.iter().zip([2.0, 2.0, 3.0]).all(|(a, b)| a.real.is_equal(b, PRECISION)));
+
+        // The eigenspace of 2 is the plane z = 0, without noise in z
+        assert_eq!(vectors.body[6].real, 0.0);
+        assert_eq!(vectors.body[7].real, 0.0);
+        let d = vectors.body[0].real * vectors.body[4].real - vectors.body[1].real * vectors.body[3].real;
+        assert!(d.abs() > 0.1);
+        for (c, l) in values.iter().enumerate() {
+            for s in 0..3 {
+                let av: f64 = (0..3).map(|k| m.body[s * 3 + k] * vectors.body[k * 3 + c].real).sum();
+                assert!(av.is_equal(l.real * vectors.body[s * 3 + c].real, PRECISION));
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrices_svd1_pos() {
+        let m = Matrix::new(2,2,vec![
+            3.0, 0.0,
+            4.0, 5.0,
+        ]).unwrap();
+        let (u, s, vt) = m.svd().unwrap();
+        let sm = Matrix::new(2,2,vec![s[0], 0.0, 0.0, s[1]]).unwrap();
+        assert!(s[0].is_equal(45.0_f64.sqrt(), PRECISION));
+        assert!(s[1].is_equal(5.0_f64.sqrt(), PRECISION));
+        assert_eq!(u * sm * vt, m);
+    }
+
+    #[test]
+    fn test_matrices_svd2_pos() {
+        let m = Matrix::new(2,3,vec![
             1.0, 2.0, 3.0,
             4.0, 5.0, 6.0,
-            7.0, 8.0, 9.0,
-        ]).unwrap();
-        let m2 = Matrix::new(3,3,vec![
-            1.0, 1.0, 2.0,
-            3.0, -4.0, 5.0,
-            6.0, 7.0, 8.0,
         ]).unwrap();
-        let test_res = Matrix::new(3,3,vec![
-            1.0, 1.0, 1.0,
-            1.0, 9.0, 1.0,
-            1.0, 1.0, 1.0,
+        let (u, s, vt) = m.svd().unwrap();
+        let e = Matrix::new(2,2,vec![1.0, 0.0, 0.0, 1.0]).unwrap();
+        let sm = Matrix::new(2,2,vec![s[0], 0.0, 0.0, s[1]]).unwrap();
+        assert_eq!(u.transpose() * u.clone(), e);
+        assert_eq!(vt.clone() * vt.transpose(), e);
+        assert_eq!((vt.transpose() * sm * u.transpose()).transpose(), m);
+    }
+
+    #[test]
+    fn test_matrices_svd3_pos() {
+        let m = Matrix::new(3,2,vec![
+            1.0, 2.0,
+            2.0, 4.0,
+            3.0, 6.0,
         ]).unwrap();
-        assert_ne!(m1 - m2, test_res);
+        let (u, s, _) = m.svd().unwrap();
+        let e = Matrix::new(2,2,vec![1.0, 0.0, 0.0, 1.0]).unwrap();
+        assert_eq!(s[1], 0.0);
+        assert_eq!(u.transpose() * u, e);
     }
 
     #[test]
-    fn test_matrices_trans1_neg() {
+    fn test_matrices_rank1_pos() {
         let m = Matrix::new(3,3,vec![
             1.0, 2.0, 3.0,
             4.0, 5.0, 6.0,
-            1.0, 2.0, 3.0,
-        ]).unwrap();
-        let test_res = Matrix::new(3,3,vec![
-            7.0, 4.0, 1.0,
-            2.0, 5.0, 2.0,
-            3.0, 6.0, 3.0,
+            7.0, 8.0, 9.0,
         ]).unwrap();
-        assert_ne!(m.transpose(), test_res);
+        assert_eq!(m.rank(1e-10).unwrap(), 2);
+        assert_eq!(m.transpose().rank(1e-10).unwrap(), 2);
     }
 
     #[test]
-    fn test_matrices_inverse1_neg() {
+    fn test_matrices_pinv1_pos() {
         let m = Matrix::new(3,3,vec![
             2.0, 1.0, 3.0,
             4.0, 1.0, 5.0,
             4.0, 5.0, 2.0,
         ]).unwrap();
-        let test_res = Matrix::new(3,3,vec![
-            -23.0/14.0, 13.0/1.0, 1.0/7.0,
-            6.0/7.0, -4.0/7.0, 1.0/7.0,
-            8.0/7.0, -3.0/7.0, -1.0/7.0,
+        assert_eq!(m.pinv().unwrap(), m.inverse().unwrap());
+    }
+
+    #[test]
+    fn test_matrices_pinv2_pos() {
+        let m = Matrix::new(3,2,vec![
+            1.0, 2.0,
+            2.0, 4.0,
+            3.0, 6.0,
         ]).unwrap();
-        assert_ne!(m.inverse().unwrap(), test_res);
+        let p = m.pinv().unwrap();
+        let test_res = Matrix::new(2,3,vec![
+            1.0 / 70.0, 2.0 / 70.0, 3.0 / 70.0,
+            2.0 / 70.0, 4.0 / 70.0, 6.0 / 70.0,
+        ]).unwrap();
+        assert_eq!(p, test_res);
+        assert_eq!(m.mul_by_ref(&p).unwrap().mul_by_ref(&m).unwrap(), m);
     }
 
     #[test]
-    fn test_matrices_inverse2_neg() {
-        let m = Matrix::new(2,3,vec![
-            2.0, 1.0, 3.0,
-            4.0, 1.0, 5.0,
+    fn test_matrices_cond1_pos() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 1.0,
+            1.0, 1.0001,
         ]).unwrap();
-        assert!(m.inverse().is_none());
+        let e = Matrix::new(2,2,vec![1.0, 0.0, 0.0, 1.0]).unwrap();
+        assert!(m.cond().unwrap().is_equal(40002.0, 0.01));
+        assert!(e.cond().unwrap().is_equal(1.0, PRECISION));
     }
 
     #[test]
-    fn test_matrices_cfm1_neg() {
-        let m = Matrix::new(2,3,vec![
-            2.0, 1.0, 3.0,
-            4.0, 1.0, 5.0,
+    fn test_matrices_cholesky1_pos() {
+        let m = Matrix::new(3,3,vec![
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0,
         ]).unwrap();
-        assert!(m.cofactor_matrix().is_none());
+        let test_res = Matrix::new(3,3,vec![
+            2.0, 0.0, 0.0,
+            6.0, 1.0, 0.0,
+            -8.0, 5.0, 3.0,
+        ]).unwrap();
+        let l = m.cholesky().unwrap();
+        assert_eq!(l, test_res);
+        assert_eq!(l.clone() * l.transpose(), m);
     }
 
     #[test]
-    fn test_matrices_pow1_neg() {
-        let m = Matrix::new(2,3,vec![
-            1.0, 1.0, 2.0,
-            3.0, -4.0, 5.0,
+    fn test_matrices_cholesky_solve1_pos() {
+        let m = Matrix::new(2,2,vec![
+            4.0, 2.0,
+            2.0, 3.0,
         ]).unwrap();
-        assert!(m.pow(3).is_err());
+        let l = m.cholesky().unwrap();
+        let x1 = l.cholesky_solve(&[6.0, 5.0]).unwrap();
+        let x2 = l.cholesky_solve(&[2.0, 3.0]).unwrap();
+        assert!(x1.iter().zip([1.0, 1.0]).all(|(a, b)| a.is_equal(b, PRECISION)));
+        assert!(x2.iter().zip([0.0, 1.0]).all(|(a, b)| a.is_equal(b, PRECISION)));
     }
 
     #[test]
-    fn test_matrices_slae1_neg() {
+    fn test_matrices_solve_spd1_pos() {
         let m = Matrix::new(3,3,vec![
-            1.0, 1.0, 2.0,
-            3.0, -4.0, 5.0,
-            3.0, -4.0, 5.0,
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0,
         ]).unwrap();
-        assert!(m.slae(&[1.0]).is_err());
+        let d = vec![vec![1.0, 2.0, 3.0], vec![0.0, -1.0, 5.0]];
+        let res = m.solve_spd(&d).unwrap();
+        for (x, d) in res.iter().zip(d.iter()) {
+            let lu_x = m.inverse().unwrap().body
+                .chunks(3)
+                .map(|s| s.iter().zip(d).map(|(a, b)| a * b).sum::<f64>())
+                .collect::<Vec<f64>>();
+            assert!(x.iter().zip(lu_x).all(|(a, b)| a.is_equal(b, PRECISION)));
+        }
+    }
+
+    #[test]
+    fn test_matrices_f32_1_pos() {
+        let m = Matrix::<f32>::new(2, 2, vec![
+            4.0, 7.0,
+            2.0, 6.0,
+        ]).unwrap();
+        let inv = Matrix::<f32>::new(2, 2, vec![
+            0.6, -0.7,
+            -0.2, 0.4,
+        ]).unwrap();
+        let x = m.slae(&[1.0, 2.0]).unwrap().unwrap();
+
+        assert!(m.det().is_equal(10.0, PRECISION));
+        assert_eq!(m.inverse().unwrap(), inv);
+        assert!(x[0].is_equal(-0.8, PRECISION) && x[1].is_equal(0.6, PRECISION));
+    }
+
+    #[test]
+    fn test_matrices_f32_2_pos() {
+        let m = Matrix::<f32>::new(3, 3, vec![
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0,
+        ]).unwrap();
+
+        let (l, u, p) = m.lu().unwrap();
+        assert_eq!(p, vec![2, 1, 0]);
+        assert!((l * u).body.iter().zip([-16.0, -43.0, 98.0, 12.0, 37.0, -43.0, 4.0, 12.0, -16.0]).all(|(a, b)| a.is_equal(b, 1e-4)));
+        let (q, r) = m.qr().unwrap();
+        assert_eq!(q * r, m);
+        let c = m.cholesky().unwrap();
+        assert_eq!(c, Matrix::<f32>::new(3, 3, vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]).unwrap());
+        let x = c.cholesky_solve(&[0.0, 6.0, 39.0]).unwrap();
+        assert!(x.iter().zip([1.0, 1.0, 1.0]).all(|(a, b)| a.is_equal(b, 1e-4)));
+
+        let (values, _) = m.eigen_sym().unwrap();
+        let (_, s, _) = m.svd().unwrap();
+        assert!(values.iter().rev().zip(&s).all(|(a, b)| a.is_equal(*b, 1e-3)));
+        assert!(m.norm_1().is_equal(157.0, PRECISION));
+        assert!(m.cond().unwrap().is_equal(m.to_f64().cond().unwrap() as f32, 1.0));
+        assert_eq!(m.powf(0.5).unwrap().powi(2).unwrap(), m);
+
+        let m = Matrix::<f32>::new(2, 3, vec![
+            1.0, 2.0, 3.0,
+            2.0, 4.0, 6.0,
+        ]).unwrap();
+        assert_eq!(m.rref().1, vec![0]);
+        assert_eq!(m.null_space().unwrap(), Matrix::<f32>::new(3, 2, vec![-2.0, -3.0, 1.0, 0.0, 0.0, 1.0]).unwrap());
+        assert!(matches!(m.solve_general(&[1.0, 2.0]).unwrap(), Solution::Infinite { .. }));
+    }
+
+    #[test]
+    fn test_matrices_f32_3_pos() {
+        let m = SMatrix::<2, 2, f32>::new([
+            [4.0, 7.0],
+            [2.0, 6.0],
+        ]);
+        assert!(m.det().is_equal(10.0, PRECISION));
+        assert_eq!(m * m.inverse().unwrap(), SMatrix::identity());
+        assert_eq!(Matrix::from(m.transpose()), Matrix::<f32>::new(2, 2, vec![4.0, 2.0, 7.0, 6.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_im_det1_pos() {
+        let m = ImMatrix::new(3, 3, vec![
+            1.r(), 2.r(), 3.r(),
+            0.r(), 1.i(), 4.r(),
+            5.r(), 6.r(), 0.r(),
+        ]).unwrap();
+        assert_eq!(m.det().round(10).to_string(), "(16-15i)");
+    }
+
+    #[test]
+    fn test_matrices_im_slae1_pos() {
+        let m = ImMatrix::new(2, 2, vec![
+            (-1).r() - 3.i(), 4.r() + 2.i(),
+            1.r() + 1.i(), (-2).r() + 1.i(),
+        ]).unwrap();
+        let x = m.slae(&[1.r() - 1.i(), 2.r() + 1.i()]).unwrap().unwrap();
+
+        assert_eq!(m.det().to_string(), "(3-i)");
+        assert_eq!(x[0].round(10).to_string(), "(-1.6-2.2i)");
+        assert_eq!(x[1].round(10).to_string(), "(0.4-2.2i)");
+    }
+
+    #[test]
+    fn test_matrices_im_inverse1_pos() {
+        let m = ImMatrix::new(2, 2, vec![
+            (-1).r() - 3.i(), 4.r() + 2.i(),
+            1.r() + 1.i(), (-2).r() + 1.i(),
+        ]).unwrap();
+        let inv = m.inverse().unwrap();
+        let test_res = ["(0.1i-0.7)", "(-1-i)", "(-0.2-0.4i)", "-i"];
+        for (e, t) in inv.body.iter().zip(test_res) {
+            assert_eq!(e.round(10).to_string(), t);
+        }
+
+        let one = ImMatrix::new(1, 1, vec![2.i()]).unwrap();
+        assert_eq!(one.inverse().unwrap().body[0].round(10).to_string(), "-0.5i");
+        assert_eq!(one.powi(-1).unwrap(), one.inverse().unwrap());
+    }
+
+    #[test]
+    fn test_matrices_im_inverse2_pos() {
+        // Symbolic elements fall back to the cofactor matrix.
+        let m = ImMatrix::new(1, 1, vec![(3.r() + 2.i()).pow(1.i())]).unwrap();
+        assert_eq!(m.det(), (3.r() + 2.i()).pow(1.i()));
+        assert!(m.inverse().is_some());
+        assert!(m.powi(-1).is_ok());
+    }
+
+    #[test]
+    fn test_matrices_im_trans1_pos() {
+        let m = ImMatrix::new(2, 3, vec![
+            1.r(), 2.i(), 3.r(),
+            4.i(), 5.r(), 6.i(),
+        ]).unwrap();
+        let t = ImMatrix::new(3, 2, vec![
+            1.r(), 4.i(),
+            2.i(), 5.r(),
+            3.r(), 6.i(),
+        ]).unwrap();
+        assert_eq!(m.transpose(), t);
+    }
+
+    #[test]
+    fn test_matrices_smatrix_mul1_pos() {
+        let m1 = SMatrix::new([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+        ]);
+        let m2 = SMatrix::new([
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 6.0],
+        ]);
+        assert_eq!(m1 * m2, SMatrix::new([[22.0, 28.0], [49.0, 64.0]]));
+        assert_eq!(m1 + m2.transpose(), SMatrix::new([[2.0, 5.0, 8.0], [6.0, 9.0, 12.0]]));
+        assert_eq!(m1 - m1, SMatrix::default());
+    }
+
+    #[test]
+    fn test_matrices_smatrix_det1_pos() {
+        let m = SMatrix::new([
+            [1.0, 4.0, 2.0],
+            [2.0, -6.0, -2.0],
+            [1.0, 5.0, 2.0],
+        ]);
+        assert!(m.det().is_equal(6.0, PRECISION));
+        assert_eq!(m * m.inverse().unwrap(), SMatrix::identity());
+        assert!(m.det().is_equal(Matrix::from(m).det(), PRECISION));
+    }
+
+    #[test]
+    fn test_matrices_smatrix_det2_pos() {
+        // Tiny pivots are not rounded to zero, as in Matrix::det.
+        let m = SMatrix::new([[1e-20, 0.0], [0.0, 1e20]]);
+        assert!(m.det().is_equal(1.0, 1e-12));
+        assert!(m.det().is_equal(Matrix::from(m).det(), 1e-12));
+        let m = SMatrix::new([[1e-3, 0.0], [0.0, 1e15]]);
+        assert!(m.det().is_equal(1e12, 1e-3));
+        assert!(m.inverse().is_none());
+        assert_eq!(SMatrix::new([[1.0, 2.0], [2.0, 4.0]]).det(), 0.0);
+    }
+
+    #[test]
+    fn test_matrices_smatrix_conv1_pos() {
+        let m = Matrix::new(2, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+        ]).unwrap();
+        let sm = SMatrix::<2, 3>::try_from(&m).unwrap();
+        assert_eq!(sm.body(), &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(Matrix::from(sm), m);
+    }
+
+    #[test]
+    fn test_matrices_sparse1_pos() {
+        let mut b = CooBuilder::new(3, 4).unwrap();
+        b.push(2, 3, 5.0).unwrap();
+        b.push(0, 1, 1.0).unwrap();
+        b.push(0, 1, 2.0).unwrap();
+        b.push(1, 0, 4.0).unwrap();
+        b.push(1, 0, -4.0).unwrap();
+        let sm = b.build();
+
+        let m = Matrix::new(3, 4, vec![
+            0.0, 3.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 5.0,
+        ]).unwrap();
+
+        assert_eq!(sm.nnz(), 2);
+        assert_eq!(sm.get(0, 1), 3.0);
+        assert_eq!(sm.get(1, 0), 0.0);
+        assert_eq!(Matrix::from(&sm), m);
+        assert_eq!(SparseMatrix::from(&m), sm);
+        assert_eq!(sm.mul_vec(&[1.0, 2.0, 3.0, 4.0]).unwrap(), Vector::new(vec![6.0, 0.0, 20.0]));
+    }
+
+    #[test]
+    fn test_matrices_sparse_cg1_pos() {
+        let n = 200;
+        let mut b = CooBuilder::new(n, n).unwrap();
+        for i in 0..n {
+            b.push(i, i, 2.0).unwrap();
+            if i > 0 { b.push(i, i - 1, -1.0).unwrap() }
+            if i < n - 1 { b.push(i, i + 1, -1.0).unwrap() }
+        }
+        let sm = b.build();
+        let d = (0..n).map(|i| (i % 7) as f64).collect::<Vec<f64>>();
+
+        let x = sm.cg(&d, 1e-12, 1000).unwrap().unwrap();
+        let ax = sm.mul_vec(&x).unwrap();
+        assert_eq!(sm.nnz(), 3 * n - 2);
+        assert!(ax.iter().zip(&d).all(|(a, b)| a.is_equal(*b, PRECISION)));
+    }
+
+    #[test]
+    fn test_matrices_iterative1_pos() {
+        let m = Matrix::new(3, 3, vec![
+            4.0, -1.0, 0.0,
+            -1.0, 4.0, -1.0,
+            0.0, -1.0, 4.0,
+        ]).unwrap();
+        let d = [2.0, 4.0, 10.0];
+        let s = IterativeSettings::default();
+        let expected = [1.0, 2.0, 3.0];
+
+        for (x, report) in [
+            jacobi(&m, &d, &s).unwrap(),
+            gauss_seidel(&m, &d, &s).unwrap(),
+            sor(&m, &d, 1.1, &s).unwrap(),
+            cg(&m, &d, &s).unwrap(),
+            gmres(&m, &d, 2, &s).unwrap(),
+        ] {
+            assert!(report.converged);
+            assert_eq!(report.residuals.len(), report.iterations + 1);
+            assert!(x.iter().zip(expected).all(|(a, b)| a.is_equal(b, PRECISION)));
+        }
+        assert!(gauss_seidel(&m, &d, &s).unwrap().1.iterations < jacobi(&m, &d, &s).unwrap().1.iterations);
+    }
+
+    #[test]
+    fn test_matrices_iterative2_pos() {
+        let n = 50;
+        let mut b = CooBuilder::new(n, n).unwrap();
+        for i in 0..n {
+            b.push(i, i, 3.0).unwrap();
+            if i > 0 { b.push(i, i - 1, -1.0).unwrap() }
+            if i < n - 1 { b.push(i, i + 1, -2.0).unwrap() }
+        }
+        let sm = b.build();
+        let d = vec![1.0; n];
+
+        let s = IterativeSettings { tolerance: 1e-12, max_iter: 500, x0: Some(vec![1.0; n]) };
+        let (x, report) = gmres(&sm, &d, 20, &s).unwrap();
+        let ax = sm.mul_vec(&x).unwrap();
+        assert!(report.converged);
+        assert!(ax.iter().zip(&d).all(|(a, b)| a.is_equal(*b, PRECISION)));
+
+        let (x0, _) = cg(&Matrix::from(&sm), &d, &IterativeSettings { max_iter: 0, ..Default::default() }).unwrap();
+        assert_eq!(x0, Vector::zeros(n));
+    }
+
+    #[test]
+    fn test_matrices_index1_pos() {
+        let mut m = Matrix::new(2, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+        ]).unwrap();
+        m[(1, 2)] = 7.0;
+        m.set(0, 0, -1.0).unwrap();
+        m.row_mut(0)[1] = 0.0;
+
+        assert_eq!(m[(1, 2)], 7.0);
+        assert_eq!(m.get(0, 0), Some(&-1.0));
+        assert_eq!(m.row(0), &[-1.0, 0.0, 3.0]);
+        assert_eq!(m.col(2), vec![3.0, 7.0]);
+        assert_eq!((m.strings(), m.rows()), (2, 3));
+    }
+
+    #[test]
+    fn test_matrices_view1_pos() {
+        let mut m = Matrix::new(3, 4, (0..12).map(|i| i as f64).collect()).unwrap();
+
+        let v = m.view(1, 1, 2, 3).unwrap();
+        assert_eq!(v[(1, 2)], 11.0);
+        assert_eq!(v.row(0), &[5.0, 6.0, 7.0]);
+        assert_eq!(v.to_matrix(), Matrix::new(2, 3, vec![5.0, 6.0, 7.0, 9.0, 10.0, 11.0]).unwrap());
+
+        let mut v = m.view_mut(0, 2, 3, 2).unwrap();
+        v[(0, 0)] = -1.0;
+        v.copy_from(&Matrix::new(3, 2, vec![0.0; 6]).unwrap()).unwrap();
+        v[(2, 1)] = 1.0;
+        assert_eq!(m.col(2), vec![0.0, 0.0, 0.0]);
+        assert_eq!(m.col(3), vec![0.0, 0.0, 1.0]);
+        assert_eq!(m.col(1), vec![1.0, 5.0, 9.0]);
+    }
+
+    #[test]
+    fn test_matrices_expm1_pos() {
+        let m = Matrix::new(2, 2, vec![
+            -49.0, 24.0,
+            -64.0, 31.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2, 2, vec![
+            -0.735759, 0.551819,
+            -1.471518, 1.103638,
+        ]).unwrap();
+        assert_eq!(m.expm().unwrap(), test_res);
+
+        let zero = Matrix::new(2, 2, vec![0.0; 4]).unwrap();
+        assert_eq!(zero.expm().unwrap(), Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_sqrtm1_pos() {
+        let m = Matrix::new(2, 2, vec![
+            33.0, 24.0,
+            48.0, 57.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2, 2, vec![
+            5.0, 2.0,
+            4.0, 7.0,
+        ]).unwrap();
+        assert_eq!(m.sqrtm().unwrap(), test_res);
+    }
+
+    #[test]
+    fn test_matrices_logm1_pos() {
+        let m = Matrix::new(3, 3, vec![
+            4.0, 1.0, 0.5,
+            1.0, 3.0, 0.2,
+            0.5, 0.2, 2.0,
+        ]).unwrap();
+        let l = m.logm().unwrap();
+        assert_eq!(l.expm().unwrap(), m);
+
+        let m = Matrix::new(2, 2, vec![
+            1.0, 1.0,
+            0.0, 1.0,
+        ]).unwrap();
+        assert_eq!(m.logm().unwrap(), Matrix::new(2, 2, vec![0.0, 1.0, 0.0, 0.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_norm1_pos() {
+        let m = Matrix::new(2, 3, vec![
+            1.0, -2.0, 3.0,
+            -4.0, 5.0, -6.0,
+        ]).unwrap();
+        assert_eq!(m.norm_1(), 9.0);
+        assert_eq!(m.norm_inf(), 15.0);
+        assert!(m.norm_fro().is_equal(91_f64.sqrt(), PRECISION));
+        assert!(m.norm_2().unwrap().is_equal(9.5080, PRECISION));
+    }
+
+    #[test]
+    fn test_matrices_cond_est1_pos() {
+        let m = Matrix::new(3, 3, vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+            1.0, 5.0, 2.0,
+        ]).unwrap();
+        let exact = m.norm_1() * m.inverse().unwrap().norm_1();
+        assert!(m.cond_est().is_equal(exact, PRECISION));
+        assert!(m.cond_est() >= m.cond().unwrap() / 3.0);
+
+        let x = m.slae_checked(&[1.0, 3.0, 2.0], 1e3).unwrap();
+        assert!(x.iter().zip([2.0, 1.0, -2.5]).all(|(a, b)| a.is_equal(b, PRECISION)));
+        assert_eq!(m.inverse_checked(1e3).unwrap(), m.inverse().unwrap());
+    }
+
+    #[test]
+    fn test_matrices_rref1_pos() {
+        let m = Matrix::new(3, 4, vec![
+            1.0, 2.0, 1.0, 4.0,
+            2.0, 4.0, 0.0, 6.0,
+            3.0, 6.0, 1.0, 10.0,
+        ]).unwrap();
+        let test_res = Matrix::new(3, 4, vec![
+            1.0, 2.0, 0.0, 3.0,
+            0.0, 0.0, 1.0, 1.0,
+            0.0, 0.0, 0.0, 0.0,
+        ]).unwrap();
+        let (r, pivots) = m.rref();
+        assert_eq!(r, test_res);
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(pivots.len(), m.rank(1e-10).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_null_space1_pos() {
+        let m = Matrix::new(3, 4, vec![
+            1.0, 2.0, 1.0, 4.0,
+            2.0, 4.0, 0.0, 6.0,
+            3.0, 6.0, 1.0, 10.0,
+        ]).unwrap();
+        let n = m.null_space().unwrap();
+        let c = m.column_space().unwrap();
+
+        assert_eq!((n.strings(), n.rows()), (4, 2));
+        assert_eq!(m.mul_by_ref(&n).unwrap(), Matrix::new(3, 2, vec![0.0; 6]).unwrap());
+        assert_eq!(c, Matrix::new(3, 2, vec![1.0, 1.0, 2.0, 0.0, 3.0, 1.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_solve_general1_pos() {
+        let m = Matrix::new(3, 3, vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+            1.0, 5.0, 2.0,
+        ]).unwrap();
+        match m.solve_general(&[1.0, 3.0, 2.0]).unwrap() {
+            Solution::Unique(x) => assert!(x.iter().zip([2.0, 1.0, -2.5]).all(|(a, b)| a.is_equal(b, PRECISION))),
+            _ => panic!("The solution must be unique."),
+        }
+    }
+
+    #[test]
+    fn test_matrices_solve_general2_pos() {
+        let m = Matrix::new(3, 4, vec![
+            1.0, 2.0, 1.0, 4.0,
+            2.0, 4.0, 0.0, 6.0,
+            3.0, 6.0, 1.0, 10.0,
+        ]).unwrap();
+        let d = [3.0, 2.0, 5.0];
+
+        let Solution::Infinite { particular, basis } = m.solve_general(&d).unwrap() else {
+            panic!("The system must have infinitely many solutions.")
+        };
+        assert_eq!((basis.strings(), basis.rows()), (4, 2));
+
+        // any particular + basis * t is a solution
+        let t = Matrix::new(2, 1, vec![1.5, -2.0]).unwrap();
+        let x = particular.to_matrix().unwrap() + basis * t;
+        assert_eq!(m * x, Matrix::new(3, 1, d.to_vec()).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_kron1_pos() {
+        let m1 = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        let m2 = Matrix::new(2, 1, vec![
+            0.0,
+            1.0,
+        ]).unwrap();
+        let test_res = Matrix::new(4, 2, vec![
+            0.0, 0.0,
+            1.0, 2.0,
+            0.0, 0.0,
+            3.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m1.kron(&m2), test_res);
+        assert_eq!(m1.hadamard(&m1).unwrap(), Matrix::new(2, 2, vec![1.0, 4.0, 9.0, 16.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_stack1_pos() {
+        let a = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        let b = Matrix::new(2, 1, vec![5.0, 6.0]).unwrap();
+        let c = Matrix::new(1, 3, vec![7.0, 8.0, 9.0]).unwrap();
+
+        let ab = a.hstack(&b).unwrap();
+        assert_eq!(ab, Matrix::new(2, 3, vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0]).unwrap());
+        assert_eq!(ab.vstack(&c).unwrap(), Matrix::from_blocks(&[vec![a, b], vec![c]]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_kron2_pos() {
+        let m1 = ImMatrix::new(1, 2, vec![1.i(), 2.r()]).unwrap();
+        let m2 = ImMatrix::new(1, 1, vec![3.i()]).unwrap();
+        assert_eq!(m1.kron(&m2), ImMatrix::new(1, 2, vec![1.i() * 3.i(), 6.i()]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_constructors1_pos() {
+        let m = Matrix::from_rows(vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+        ]).unwrap();
+        assert_eq!(Matrix::identity(2).unwrap(), m);
+        assert_eq!(Matrix::from_diagonal(&[1.0, 1.0]).unwrap(), m);
+        assert_eq!(Matrix::from_fn(2, 2, |s, r| (s == r) as u8 as f64).unwrap(), m);
+        assert_eq!(Matrix::<f64>::zeros(2, 3).unwrap(), Matrix::new(2, 3, vec![0.0; 6]).unwrap());
+        assert_eq!(Matrix::<f64>::ones(3, 2).unwrap(), Matrix::new(3, 2, vec![1.0; 6]).unwrap());
+        assert_eq!(ImMatrix::identity(2).unwrap(), ImMatrix::from_diagonal(&[1.r(), 1.r()]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_constructors2_pos() {
+        let h = Matrix::hilbert(3).unwrap();
+        assert!(h.det().is_equal(1.0 / 2160.0, 1e-12));
+        assert_eq!(h[(1, 2)], 0.25);
+
+        let v = Matrix::vandermonde(&[1.0, 2.0, 3.0]).unwrap();
+        assert!(v.det().is_equal(2.0, PRECISION));
+
+        let t = Matrix::toeplitz(&[1.0, 2.0, 3.0], &[1.0, 4.0, 5.0]).unwrap();
+        assert_eq!(t, Matrix::new(3, 3, vec![
+            1.0, 4.0, 5.0,
+            2.0, 1.0, 4.0,
+            3.0, 2.0, 1.0,
+        ]).unwrap());
+
+        let c = Matrix::circulant(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(c, Matrix::toeplitz(&[1.0, 2.0, 3.0], &[1.0, 3.0, 2.0]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_pow4_pos() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 1.0,
+            1.0, 0.0,
+        ]).unwrap();
+        assert_eq!(m.pow(0).unwrap(), Matrix::identity(2).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_io1_pos() {
+        let test_res = Matrix::new(2, 3, vec![
+            1.0, -2.0, 3.5,
+            4.0, 5e-1, 6.0,
+        ]).unwrap();
+        assert_eq!("[1 -2 3.5; 4 5e-1 6]".parse::<Matrix>().unwrap(), test_res);
+        assert_eq!("1, -2, 3.5\n4, 0.5, 6\n".parse::<Matrix>().unwrap(), test_res);
+        assert_eq!("[1 -2 3.5\n 4 0.5 6]".parse::<Matrix>().unwrap(), test_res);
+
+        let m: ImMatrix = "[1+2i -i; 3 4.5i; 1-i 0]".parse().unwrap();
+        assert_eq!(m, ImMatrix::new(3, 2, vec![
+            1.r() + 2.i(), (-1).i(),
+            3.r(), 4.5.i(),
+            1.r() - 1.i(), 0.r(),
+        ]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_io2_pos() {
+        let m = Matrix::new(2, 3, vec![
+            1.0, -2.0, 0.0,
+            0.125, 5.0, 1e-20,
+        ]).unwrap();
+
+        let mut csv = Vec::new();
+        m.write_csv(&mut csv).unwrap();
+        assert_eq!(Matrix::read_csv(&csv[..]).unwrap().body, m.body);
+
+        for format in [MarketFormat::Array, MarketFormat::Coordinate] {
+            let mut mtx = Vec::new();
+            m.write_matrix_market(&mut mtx, format).unwrap();
+            assert_eq!(Matrix::read_matrix_market(&mtx[..]).unwrap().body, m.body);
+        }
+
+        let sparse = SparseMatrix::from(&m);
+        let mut mtx = Vec::new();
+        sparse.write_matrix_market(&mut mtx).unwrap();
+        assert_eq!(Matrix::from(&SparseMatrix::read_matrix_market(&mtx[..]).unwrap()), Matrix::from(&sparse));
+    }
+
+    #[test]
+    fn test_matrices_io3_pos() {
+        let text = "%%MatrixMarket matrix array real symmetric
+            % lower triangle by columns
+            3 3
+            1
+            2
+            3
+            4
+            5
+            6
+        ";
+        let test_res = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            2.0, 4.0, 5.0,
+            3.0, 5.0, 6.0,
+        ]).unwrap();
+        assert_eq!(Matrix::read_matrix_market(text.as_bytes()).unwrap(), test_res);
+
+        let text = "%%MatrixMarket matrix coordinate pattern skew-symmetric\n3 3 2\n2 1\n3 1\n";
+        let test_res = Matrix::new(3, 3, vec![
+            0.0, -1.0, -1.0,
+            1.0, 0.0, 0.0,
+            1.0, 0.0, 0.0,
+        ]).unwrap();
+        assert_eq!(Matrix::read_matrix_market(text.as_bytes()).unwrap(), test_res);
+    }
+
+    #[test]
+    fn test_matrices_ops1_pos() {
+        let a = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        let b = Matrix::identity(2).unwrap();
+
+        assert_eq!(&a + &b, a.clone() + b.clone());
+        assert_eq!(&a - &b, a.clone() - b.clone());
+        assert_eq!(&a * &a, Matrix::new(2, 2, vec![7.0, 10.0, 15.0, 22.0]).unwrap());
+        assert_eq!(&a * 2.0, 2.0 * &a);
+        assert_eq!(-&a, a.mul_num(-1.0));
+        assert_eq!(-(-a.clone()), a);
+
+        let mut m = a.clone();
+        m += &b;
+        m -= b.clone();
+        assert_eq!(m, a);
+        m *= 3.0;
+        m *= &b;
+        assert_eq!(m, a.clone() * 3.0);
+        m *= b;
+        assert_eq!(m, 3.0 * a);
+    }
+
+    #[test]
+    fn test_matrices_ops2_pos() {
+        let a = ImMatrix::new(1, 2, vec![1.i(), 2.r()]).unwrap();
+        let b = ImMatrix::new(1, 2, vec![1.r(), 1.i()]).unwrap();
+
+        assert_eq!(&a + &b, ImMatrix::new(1, 2, vec![1.i() + 1.r(), 2.r() + 1.i()]).unwrap());
+        assert_eq!(-&a, ImMatrix::new(1, 2, vec![(-1).i(), (-2).r()]).unwrap());
+        assert_eq!(2.i() * &a, ImMatrix::new(1, 2, vec![2.i() * 1.i(), 2.i() * 2.r()]).unwrap());
+        assert_eq!(&a * 2.r(), a.mul_num(2.r()));
+
+        let mut m = a.clone();
+        m -= &a;
+        assert_eq!(m, ImMatrix::new(1, 2, vec![0.r(), 0.r()]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_export1_pos() {
+        let m = Matrix::new(2, 3, vec![
+            1.0, -2.5, 3.0,
+            10.0, 0.0, -1.25,
+        ]).unwrap();
+
+        assert_eq!(format!("{}", m), "Matrix (2x3) = [\n  1 -2.5     3\n 10    0 -1.25\n]");
+        assert_eq!(format!("{:6.2}", m), m.pretty(Some(2), 6));
+        assert_eq!(m.pretty(Some(2), 6), "Matrix (2x3) = [\n   1.00  -2.50   3.00\n  10.00   0.00  -1.25\n]");
+        assert_eq!(m.to_latex(Some(1)), "\\begin{bmatrix}\n1.0 & -2.5 & 3.0 \\\\\n10.0 & 0.0 & -1.2\n\\end{bmatrix}");
+        assert_eq!(m.to_markdown(None), "| 1 | 2 | 3 |\n|---:|---:|---:|\n| 1 | -2.5 | 3 |\n| 10 | 0 | -1.25 |");
+        assert_eq!(m.to_numpy(None), "np.array([[1.0, -2.5, 3.0], [10.0, 0.0, -1.25]])");
+        assert_eq!(m.to_numpy(Some(0)), "np.array([[1.0, -2.0, 3.0], [10.0, 0.0, -1.0]])");
+
+        let m = Matrix::<f32>::new(1, 3, vec![0.1, f32::NAN, f32::NEG_INFINITY]).unwrap();
+        assert_eq!(m.to_numpy(None), "np.array([[0.1, np.nan, -np.inf]])");
+    }
+
+    #[test]
+    fn test_matrices_export2_pos() {
+        let m = ImMatrix::new(2, 2, vec![
+            1.r() + 2.i(), (-1).i(),
+            3.r(), 2.i() * 2.i(),
+        ]).unwrap();
+
+        assert_eq!(format!("{}", m), "Matrix (2x2) = [\n (1+2i) -i\n      3 -4\n]");
+        assert_eq!(m.to_latex(None), "\\begin{bmatrix}\n(1+2i) & -i \\\\\n3 & -4\n\\end{bmatrix}");
+        assert_eq!(m.to_markdown(None), "| 1 | 2 |\n|---:|---:|\n| (1+2i) | -i |\n| 3 | -4 |");
+        assert_eq!(m.to_numpy(None), "np.array([[(1+2j), -1j], [3, -4]])");
+    }
+
+    #[test]
+    fn test_matrices_pow5_pos() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 1.0,
+            1.0, 0.0,
+        ]).unwrap();
+        let mut res = Matrix::identity(2).unwrap();
+        for p in 0..20 {
+            assert_eq!(m.pow(p).unwrap(), res);
+            res *= &m;
+        }
+    }
+
+    #[test]
+    fn test_matrices_powi1_pos() {
+        let m = Matrix::new(2,2,vec![
+            2.0, 1.0,
+            1.0, 1.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2,2,vec![
+            2.0, -3.0,
+            -3.0, 5.0,
+        ]).unwrap();
+        assert_eq!(m.powi(-2).unwrap(), test_res);
+        assert_eq!(m.powi(3).unwrap(), m.pow(3).unwrap());
+        assert_eq!(m.powi(0).unwrap(), Matrix::identity(2).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_powf1_pos() {
+        let m = Matrix::new(2,2,vec![
+            5.0, 4.0,
+            4.0, 5.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2,2,vec![
+            2.0, 1.0,
+            1.0, 2.0,
+        ]).unwrap();
+        assert_eq!(m.powf(0.5).unwrap(), test_res);
+        assert_eq!(m.powf(-1.0).unwrap(), m.inverse().unwrap());
+    }
+
+    #[test]
+    fn test_matrices_powf2_pos() {
+        let m = Matrix::new(2,2,vec![
+            4.0, 1.0,
+            0.0, 9.0,
+        ]).unwrap();
+        let r = m.powf(0.5).unwrap();
+        assert_eq!(r.mul_by_ref(&r).unwrap(), m);
+        let r = m.powf(1.5).unwrap();
+        assert_eq!(r, m.mul_by_ref(&m.powf(0.5).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_solve1_pos() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+            1.0, 5.0, 2.0,
+        ]).unwrap();
+        let d = Vector::new(vec![1.0, 3.0, 2.0]);
+        let x = m.solve(&d).unwrap();
+        assert_eq!(x, Vector::new(vec![2.0, 1.0, -2.5]));
+        assert_eq!(&m * &x, d);
+        assert_eq!(x, m.slae(&d).unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_matrices_solve2_pos() {
+        let m = Matrix::new(3,3,vec![
+            4.0, -1.0, 0.0,
+            -1.0, 4.0, -1.0,
+            0.0, -1.0, 4.0,
+        ]).unwrap();
+        let d = Vector::new(vec![2.0, 4.0, 10.0]);
+        let x = Vector::new(vec![1.0, 2.0, 3.0]);
+        let settings = IterativeSettings::default();
+
+        assert_eq!(m.slae(&d).unwrap().unwrap(), x);
+        assert_eq!(m.slae_checked(&d, 1e10).unwrap(), x);
+        assert_eq!(m.lstsq(&d).unwrap().unwrap().0, x);
+        assert_eq!(m.cholesky().unwrap().cholesky_solve(&d).unwrap(), x);
+        assert_eq!(m.solve_spd(&[d.to_vec()]).unwrap(), vec![x.clone()]);
+        assert_eq!(m.solve_general(&d).unwrap(), Solution::Unique(x.clone()));
+        assert_eq!(jacobi(&m, &d, &settings).unwrap().0, x);
+        assert_eq!(gauss_seidel(&m, &d, &settings).unwrap().0, x);
+        assert_eq!(sor(&m, &d, 1.1, &settings).unwrap().0, x);
+        assert_eq!(cg(&m, &d, &settings).unwrap().0, x);
+        assert_eq!(gmres(&m, &d, 3, &settings).unwrap().0, x);
+
+        let sm = SparseMatrix::from(&m);
+        assert_eq!(sm.mul_vec(&x).unwrap(), d);
+        let y = sm.cg(&d, 1e-10, 100).unwrap().unwrap();
+        assert_eq!(&m * &y, d);
+    }
+
+    #[test]
+    fn test_matrices_export3_pos() {
+        let m = ImMatrix::new(1, 2, vec![
+            (1.0 / 3.0).r() + 2.i(), (1.r() - (1.0 / 7.0).i()).pow(2.i()),
+        ]).unwrap();
+
+        assert_eq!(format!("{:.2}", m), "Matrix (1x2) = [\n (0.33+2i) (1-0.14i)^2i\n]");
+        assert_eq!(m.to_latex(Some(2)), "\\begin{bmatrix}\n(0.33+2i) & (1-0.14i)^2i\n\\end{bmatrix}");
+        assert_eq!(m.to_numpy(Some(2)), "np.array([[(0.33+2j), (1-0.14j)**2j]])");
+        assert_eq!(m.to_markdown(Some(1)), "| 1 | 2 |\n|---:|---:|\n| (0.3+2i) | (1-0.1i)^2i |");
+        assert_eq!(format!("{:.0}", (-0.001).r() + 2.i()), "2i");
+    }
+
+    #[test]
+    fn test_matrices1_neg() {
+        let m = Matrix::new(3,3,vec![
+            131.0, 231.0, 5.4,
+            0.0, -0.0, 4.5,
+        ]);
+        assert!(m.is_err());
+    }
+
+    #[test]
+    fn test_matrices2_neg() {
+        let m = Matrix::new(2,2,vec![
+            131.0, 231.0, 5.4,
+            0.0, -0.0, 4.5,
+        ]);
+        assert!(m.is_err());
+    }
+
+    #[test]
+    fn test_matrices3_neg() {
+        let m = Matrix::<f64>::new(2,2,vec![]);
+        assert!(m.is_err());
+    }
+
+    #[test]
+    fn test_matrices4_neg() {
+        let m = Matrix::new(2,0,vec![
+            131.0, 231.0, 5.4,
+            0.0, -0.0, 4.5,
+        ]);
+        assert!(m.is_err());
+    }
+
+    #[test]
+    fn test_matrices5_neg() {
+        let m = Matrix::new(0,2,vec![
+            131.0, 231.0, 5.4,
+            0.0, -0.0, 4.5,
+        ]);
+        assert!(m.is_err());
+    }
+
+    #[test]
+    fn test_matrices_lu1_neg() {
+        let m = Matrix::new(2,3,vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+        ]).unwrap();
+        assert!(m.lu().is_err());
+    }
+
+    #[test]
+    fn test_matrices_lstsq1_neg() {
+        let m = Matrix::new(2,3,vec![
+            1.0, 4.0, 2.0,
+            2.0, -6.0, -2.0,
+        ]).unwrap();
+        assert!(m.lstsq(&[1.0, 2.0]).unwrap().is_none());
+        assert!(m.lstsq(&[1.0, 2.0, 3.0]).is_err());
+    }
+
+    #[test]
+    fn test_matrices_lstsq2_neg() {
+        let m = Matrix::new(3,2,vec![
+            1.0, 2.0,
+            2.0, 4.0,
+            3.0, 6.0,
+        ]).unwrap();
+        assert!(m.lstsq(&[1.0, 2.0, 3.0]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_matrices_eigen_sym1_neg() {
+        let m = Matrix::new(2,2,vec![
+            2.0, 1.0,
+            1.1, 2.0,
+        ]).unwrap();
+        assert!(!m.is_symmetric(PRECISION));
+        assert!(m.eigen_sym().is_err());
+    }
+
+    #[test]
+    fn test_matrices_eigen_sym2_neg() {
+        let m = Matrix::new(2,3,vec![
+            2.0, 1.0, 0.0,
+            1.0, 2.0, 0.0,
+        ]).unwrap();
+        assert!(m.eigen_sym().is_err());
+    }
+
+    #[test]
+    fn test_matrices_eigen_sym3_neg() {
+        // Small, but visibly asymmetric.
+        let m = Matrix::new(2,2,vec![
+            1e-6, 1e-5,
+            0.0, 1e-6,
+        ]).unwrap();
+        assert!(m.is_symmetric(PRECISION));
+        assert_eq!(m.eigen_sym().unwrap_err(), MatrixError::NotSymmetric);
+        assert_eq!(m.cholesky().unwrap_err(), MatrixError::NotSymmetric);
+    }
+
+    #[test]
+    fn test_matrices_eigenvalues1_neg() {
+        let m = Matrix::new(2,3,vec![
+            2.0, 1.0, 0.0,
+            1.0, 2.0, 0.0,
+        ]).unwrap();
+        assert!(m.eigenvalues().is_err());
+        assert!(m.eigen().is_err());
+    }
+
+    #[test]
+    fn test_matrices_eigen1_neg() {
+        let m = Matrix::new(3,3,vec![
+            2.0, 1.0, 0.0,
+            0.0, 2.0, 0.0,
+            0.0, 0.0, 3.0,
+        ]).unwrap();
+        assert_eq!(m.eigen().unwrap_err(), MatrixError::NotDiagonalizable);
+        assert_eq!(m.eigen().unwrap_err().to_string(), "The matrix is not diagonalizable.");
+        assert!(m.eigenvalues().is_ok());
+
+        let m = Matrix::new(2,2,vec![
+            1e6, 1e6,
+            0.0, 1e6,
+        ]).unwrap();
+        assert_eq!(m.eigen().unwrap_err(), MatrixError::NotDiagonalizable);
+    }
+
+    #[test]
+    fn test_matrices_cond1_neg() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 2.0,
+            2.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m.cond().unwrap(), f64::INFINITY);
+        assert_eq!(m.rank(1e-10).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_matrices_svd1_neg() {
+        let m = Matrix::new(2,2,vec![
+            1.0, f64::NAN,
+            2.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m.svd().unwrap_err(), MatrixError::NotConverged);
+        assert_eq!(m.rank(1e-10).unwrap_err(), MatrixError::NotConverged);
+        assert_eq!(m.pinv().unwrap_err(), MatrixError::NotConverged);
+        assert_eq!(m.cond().unwrap_err(), MatrixError::NotConverged);
+        assert_eq!(m.norm_2().unwrap_err(), MatrixError::NotConverged);
+    }
+
+    #[test]
+    fn test_matrices_cholesky1_neg() {
+        let m = Matrix::new(3,3,vec![
+            4.0, 2.0, 0.0,
+            2.0, 1.0, 0.0,
+            0.0, 0.0, 5.0,
+        ]).unwrap();
+        let err = m.cholesky().unwrap_err();
+        assert_eq!(err, MatrixError::NotPositiveDefinite { pivot: 2 });
+        assert!(err.to_string().contains("pivot 2"));
+    }
+
+    #[test]
+    fn test_matrices_cholesky2_neg() {
+        let m = Matrix::new(2,2,vec![
+            4.0, 2.0,
+            1.0, 3.0,
+        ]).unwrap();
+        assert!(m.cholesky().is_err());
+        assert!(m.solve_spd(&[vec![1.0, 2.0]]).is_err());
+    }
+
+    #[test]
+    fn test_matrices_cholesky_solve1_neg() {
+        let m = Matrix::new(2,2,vec![
+            4.0, 2.0,
+            2.0, 3.0,
+        ]).unwrap();
+        assert!(m.cholesky().unwrap().cholesky_solve(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_matrices_im_inverse1_neg() {
+        let m = ImMatrix::new(2, 2, vec![
+            1.i(), 2.i(),
+            2.i(), 4.i(),
+        ]).unwrap();
+        assert!(m.inverse().is_none());
+        assert!(m.slae(&[1.r(), 1.r()]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_matrices_smatrix1_neg() {
+        let m = Matrix::new(2, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+        ]).unwrap();
+        assert!(SMatrix::<3, 2>::try_from(m).is_err());
+
+        let s = SMatrix::new([
+            [1.0, 2.0],
+            [2.0, 4.0],
+        ]);
+        assert_eq!(s.det(), 0.0);
+        assert!(s.inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrices_sparse1_neg() {
+        let mut b = CooBuilder::new(2, 3).unwrap();
+        assert!(b.push(2, 0, 1.0).is_err());
+        assert!(CooBuilder::new(0, 3).is_err());
+
+        b.push(0, 0, 1.0).unwrap();
+        let sm = b.build();
+        assert!(sm.mul_vec(&[1.0, 2.0]).is_err());
+        assert!(sm.cg(&[1.0, 2.0], 1e-10, 100).is_err());
+    }
+
+    #[test]
+    fn test_matrices_sparse_cg1_neg() {
+        let m = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            2.0, 1.0,
+        ]).unwrap();
+        let sm = SparseMatrix::from(&m);
+        assert!(sm.cg(&[1.0, 0.0], 1e-10, 100).is_err());
+        assert!(sm.cg(&[1.0, 3.0], 1e-10, 0).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_matrices_iterative1_neg() {
+        let m = Matrix::new(2, 2, vec![
+            0.0, 1.0,
+            1.0, 0.0,
+        ]).unwrap();
+        let s = IterativeSettings::default();
+        assert!(jacobi(&m, &[1.0, 1.0], &s).is_err());
+        assert!(sor(&m, &[1.0, 1.0], 2.0, &s).is_err());
+        assert!(cg(&m, &[1.0], &s).is_err());
+        assert_eq!(cg(&m, &[1.0, 0.0], &s).unwrap_err(), MatrixError::NegativeCurvature { iteration: 1 });
+        assert!(gmres(&m, &[1.0, 1.0], 0, &s).is_err());
+
+        let s = IterativeSettings { x0: Some(vec![0.0; 3]), ..Default::default() };
+        assert!(gmres(&m, &[1.0, 1.0], 2, &s).is_err());
+    }
+
+    #[test]
+    fn test_matrices_iterative2_neg() {
+        // Not diagonally dominant, the Jacobi method diverges.
+        let m = Matrix::new(2, 2, vec![
+            1.0, 3.0,
+            3.0, 1.0,
+        ]).unwrap();
+        let s = IterativeSettings { max_iter: 50, ..Default::default() };
+        let (_, report) = jacobi(&m, &[1.0, 2.0], &s).unwrap();
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 50);
+    }
+
+    #[test]
+    fn test_matrices_index1_neg() {
+        let mut m = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        assert!(m.get(0, 2).is_none());
+        assert!(m.set(2, 0, 1.0).is_err());
+        assert!(m.view(1, 1, 2, 1).is_err());
+        assert!(m.view_mut(0, 0, 0, 1).is_err());
+
+        let v = m.view(0, 0, 1, 2).unwrap();
+        assert!(v.get(1, 0).is_none());
+        assert!(m.view_mut(0, 0, 2, 2).unwrap().copy_from(&Matrix::new(1, 2, vec![0.0; 2]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_matrices_functions1_neg() {
+        let m = Matrix::new(2, 3, vec![0.0; 6]).unwrap();
+        assert!(m.expm().is_err());
+        assert!(m.sqrtm().is_err());
+        assert!(m.logm().is_err());
+
+        let m = Matrix::new(2, 2, vec![
+            -1.0, 0.0,
+            0.0, -1.0,
+        ]).unwrap();
+        assert!(m.sqrtm().is_err());
+        assert!(m.logm().is_err());
+        assert!(Matrix::new(1, 1, vec![f64::NAN]).unwrap().expm().is_err());
+    }
+
+    #[test]
+    fn test_matrices_cond_est1_neg() {
+        let m = Matrix::new(2, 2, vec![
+            1.0, 1.0,
+            1.0, 1.0 + 1e-12,
+        ]).unwrap();
+        assert!(m.cond_est() > 1e11);
+        assert!(matches!(m.inverse_checked(1e10), Err(MatrixError::IllConditioned { cond }) if cond > 1e11));
+        assert!(m.slae_checked(&[1.0, 2.0], 1e10).is_err());
+
+        let m = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            2.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m.cond_est(), f64::INFINITY);
+        assert_eq!(m.inverse_checked(f64::MAX).unwrap_err(), MatrixError::Singular);
+        assert_eq!(Matrix::new(1, 2, vec![1.0, 2.0]).unwrap().inverse_checked(1e10).unwrap_err(), MatrixError::NotSquare { strings: 1, rows: 2 });
+    }
+
+    #[test]
+    fn test_matrices_null_space1_neg() {
+        let m = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        assert!(m.null_space().is_none());
+
+        let zero = Matrix::new(2, 3, vec![0.0; 6]).unwrap();
+        assert!(zero.column_space().is_none());
+        assert!(zero.rref().1.is_empty());
+    }
+
+    #[test]
+    fn test_matrices_solve_general1_neg() {
+        let m = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            2.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m.solve_general(&[1.0, 3.0]).unwrap(), Solution::Inconsistent);
+        assert!(m.solve_general(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_matrices_stack1_neg() {
+        let a = Matrix::new(2, 2, vec![0.0; 4]).unwrap();
+        let b = Matrix::new(1, 3, vec![0.0; 3]).unwrap();
+
+        assert!(a.hadamard(&b).is_err());
+        assert!(a.hstack(&b).is_err());
+        assert!(a.vstack(&b).is_err());
+        assert!(Matrix::<f64>::from_blocks(&[]).is_err());
+        assert_eq!(Matrix::from_blocks(&[vec![a.clone()], vec![]]).unwrap_err(), MatrixError::Empty);
+        assert_eq!(
+            Matrix::from_blocks(&[vec![a.clone(), b.clone()]]).unwrap_err(),
+            MatrixError::BlockMismatch { block: (1, 2), expected: 2, found: 1 }
+        );
+        let err = Matrix::from_blocks(&[vec![a], vec![b]]).unwrap_err();
+        assert_eq!(err, MatrixError::BlockMismatch { block: (2, 0), expected: 2, found: 3 });
+        assert_eq!(err.to_string(), "The block string 2 has 3 rows instead of 2.");
+    }
+
+    #[test]
+    fn test_matrices_constructors1_neg() {
+        assert!(Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0]]).is_err());
+        assert!(Matrix::<f64>::from_rows(vec![]).is_err());
+        assert!(Matrix::<f64>::identity(0).is_err());
+        assert!(Matrix::<f64>::zeros(0, 2).is_err());
+        assert!(Matrix::circulant(&[]).is_err());
+    }
+
+    #[test]
+    fn test_matrices_io1_neg() {
+        let err = |l, c| Err::<Matrix, _>((l, c));
+        let pos = |r: Result<Matrix, ParseError>| r.map_err(|e| (e.line, e.column));
+
+        assert_eq!(pos("[1 2; 3 x]".parse()), err(1, 9));
+        assert_eq!(pos("[1 2\n3]".parse()), err(2, 1));
+        assert_eq!(pos("[1 2\n3 4".parse()), err(2, 1));
+        assert_eq!(pos("[1 2] 3".parse()), err(1, 7));
+        assert!("[1 2+]".parse::<ImMatrix>().is_err());
+        assert!("".parse::<Matrix>().is_err());
+
+        let csv = |s: &str| match Matrix::read_csv(s.as_bytes()) {
+            Err(ReadError::Parse(e)) => (e.line, e.column),
+            r => panic!("{:?}", r),
+        };
+        assert_eq!(csv("1,2\n3,,4"), (2, 3));
+        assert_eq!(csv("1,2\n\n3, abc"), (3, 4));
+        assert_eq!(csv("1,2\n3"), (2, 1));
+
+        let mtx = |s: &str| match Matrix::read_matrix_market(s.as_bytes()) {
+            Err(ReadError::Parse(e)) => (e.line, e.column),
+            r => panic!("{:?}", r),
+        };
+        assert_eq!(mtx("%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 0\n"), (1, 34));
+        assert_eq!(mtx("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 3 1.0\n"), (3, 3));
+        assert_eq!(mtx("%%MatrixMarket matrix array real general\n2 1\n1.0\n"), (4, 1));
+        assert_eq!(mtx("%%MatrixMarket matrix array real general\n1 1\n1.0\n2.0\n"), (4, 1));
+
+        assert!(matches!(Matrix::read_csv(&[0xff][..]), Err(ReadError::Io(_))));
+        assert!(matches!(SparseMatrix::read_matrix_market("1 1 1".as_bytes()), Err(ReadError::Parse(_))));
+    }
+
+    #[test]
+    fn test_matrices_error1_neg() {
+        let mut m = Matrix::new(2, 3, vec![0.0; 6]).unwrap();
+        assert_eq!(Matrix::<f64>::new(0, 3, vec![]).unwrap_err(), MatrixError::Empty);
+        assert_eq!(m.pow(2).unwrap_err(), MatrixError::NotSquare { strings: 2, rows: 3 });
+        assert_eq!(m.set(2, 0, 1.0).unwrap_err(), MatrixError::IndexOutOfBounds { index: (2, 0), shape: (2, 3) });
+        assert_eq!(m.mul_by_ref(&m).unwrap_err(), MatrixError::DimensionMismatch { left: (2, 3), right: (2, 3) });
+        assert_eq!(m.solve_general(&[1.0]).unwrap_err(), MatrixError::DimensionMismatch { left: (2, 3), right: (1, 1) });
+
+        let m = ImMatrix::new(2, 2, vec![1.i(), 2.r(), 3.r(), 4.i()]).unwrap();
+        assert_eq!(m.slae(&[1.r()]).unwrap_err(), MatrixError::DimensionMismatch { left: (2, 2), right: (1, 1) });
+        assert_eq!(m.view(1, 1, 2, 1).unwrap_err().to_string(), "The index (2, 1) is out of the matrix 2x2.");
+    }
+
+    #[test]
+    fn test_matrices_ops1_neg() {
+        let a = Matrix::new(2, 2, vec![0.0; 4]).unwrap();
+        let b = Matrix::new(3, 3, vec![0.0; 9]).unwrap();
+        let mismatch = MatrixError::DimensionMismatch { left: (2, 2), right: (3, 3) };
+
+        assert_eq!(a.checked_add(&b).unwrap_err(), mismatch);
+        assert_eq!(a.checked_sub(&b).unwrap_err(), mismatch);
+        assert_eq!(a.checked_mul(&b).unwrap_err(), mismatch);
+        assert!(ImMatrix::new(1, 1, vec![1.i()]).unwrap().checked_add(&ImMatrix::new(1, 2, vec![1.i(), 1.r()]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_matrices_ops2_neg() {
+        // b * a exists, a * b does not, the operands are never swapped.
+        let a = Matrix::new(2, 3, vec![1.0; 6]).unwrap();
+        let b = Matrix::new(2, 2, vec![1.0; 4]).unwrap();
+
+        assert_eq!(a.checked_mul(&b).unwrap_err(), MatrixError::DimensionMismatch { left: (2, 3), right: (2, 2) });
+        assert!(a.mul_by_ref(&b).is_err());
+        assert_eq!(b.checked_mul(&a).unwrap(), Matrix::new(2, 3, vec![2.0; 6]).unwrap());
+    }
+
+    #[test]
+    fn test_matrices_powi1_neg() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 2.0,
+            2.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m.powi(-1).unwrap_err(), MatrixError::Singular);
+        assert!(m.powi(2).is_ok());
+    }
+
+    #[test]
+    fn test_matrices_powf1_neg() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 2.0,
+            2.0, 1.0,
+        ]).unwrap();
+        assert_eq!(m.powf(0.5).unwrap_err(), MatrixError::NonPositiveEigenvalue { index: 1 });
+
+        let m = Matrix::new(2,2,vec![
+            1.0, 1.0,
+            0.0, 1.0,
+        ]).unwrap();
+        assert!(matches!(m.powf(0.5), Err(MatrixError::NotDiagonalizable)));
+
+        let m = Matrix::new(2,2,vec![
+            0.0, -1.0,
+            1.0, 0.0,
+        ]).unwrap();
+        assert!(matches!(m.powf(0.5), Err(MatrixError::InvalidArgument(_))));
+        assert!(matches!(m.powf(f64::NAN), Err(MatrixError::InvalidArgument(_))));
+
+        let m = Matrix::new(2,2,vec![
+            1.0, 1.0,
+            0.0, -2.0,
+        ]).unwrap();
+        assert_eq!(m.powf(0.5).unwrap_err(), MatrixError::NonPositiveEigenvalue { index: 1 });
+    }
+
+    #[test]
+    fn test_matrices_solve1_neg() {
+        let m = Matrix::new(2,2,vec![
+            1.0, 2.0,
+            2.0, 4.0,
+        ]).unwrap();
+        assert_eq!(m.solve(&[1.0, 1.0]).unwrap_err(), MatrixError::Singular);
+        assert!(m.solve(&[1.0]).is_err());
+        let m = Matrix::new(1, 2, vec![1.0, 2.0]).unwrap();
+        assert!(matches!(m.solve(&[1.0]), Err(MatrixError::NotSquare { .. })));
+    }
+
+    #[test]
+    fn test_matrices_mul1_neg() {
+        let m1 = Matrix::new(2,1,vec![
+            1.0,
+            2.0,
+        ]).unwrap();
+        let m2 = Matrix::new(3,2,vec![
+            1.0, 2.0,
+            3.0, 4.0,
+            1.0, 2.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2,1, vec![
+            5.0,
+            11.0,
+        ]).unwrap();
+        assert!(m1.checked_mul(&m2).is_err());
+        assert_ne!(m2 * m1, test_res);
+    }
+
+    #[test]
+    fn test_matrices_mul2_neg() {
+        let m1 = Matrix::new(3,2,vec![
+            1.0, 2.0,
+            3.0, 4.0,
+            1.0, 2.0,
+        ]).unwrap();
+        let m2 = Matrix::new(2,1,vec![
+            1.0,
+            2.0,
+        ]).unwrap();
+        let test_res = Matrix::new(2,1, vec![
+            5.0,
+            11.0,
+        ]).unwrap();
+        assert_ne!(m1 * m2, test_res);
+    }
+
+    #[test]
+    fn test_matrices_add1_neg() {
+        let m1 = Matrix::new(3,3,vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        ]).unwrap();
+        let m2 = Matrix::new(3,3,vec![
+            1.0, 1.0, 2.0,
+            3.0, -4.0, 5.0,
+            6.0, 7.0, 8.0,
+        ]).unwrap();
+        let test_res = Matrix::new(3,3,vec![
+            1.0, 3.0, 5.0,
+            7.0, 1.0, 11.0,
+            13.0, 15.0, 17.0,
+        ]).unwrap();
+        assert_ne!(m1 + m2, test_res);
+    }
+
+    #[test]
+    fn test_matrices_add2_neg() {
+        let m = Matrix::<f64>::identity(3).unwrap();
+        assert_eq!(m.add_by_ref(&Matrix::identity(2).unwrap()).unwrap_err(), MatrixError::DimensionMismatch { left: (3, 3), right: (2, 2) });
+    }
+
+    #[test]
+    fn test_matrices_sub1_neg() {
+        let m1 = Matrix::new(3,3,vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        ]).unwrap();
+        let m2 = Matrix::new(3,3,vec![
+            1.0, 1.0, 2.0,
+            3.0, -4.0, 5.0,
+            6.0, 7.0, 8.0,
+        ]).unwrap();
+        let test_res = Matrix::new(3,3,vec![
+            1.0, 1.0, 1.0,
+            1.0, 9.0, 1.0,
+            1.0, 1.0, 1.0,
+        ]).unwrap();
+        assert_ne!(m1 - m2, test_res);
+    }
+
+    #[test]
+    fn test_matrices_trans1_neg() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            1.0, 2.0, 3.0,
+        ]).unwrap();
+        let test_res = Matrix::new(3,3,vec![
+            7.0, 4.0, 1.0,
+            2.0, 5.0, 2.0,
+            3.0, 6.0, 3.0,
+        ]).unwrap();
+        assert_ne!(m.transpose(), test_res);
+    }
+
+    #[test]
+    fn test_matrices_inverse1_neg() {
+        let m = Matrix::new(3,3,vec![
+            2.0, 1.0, 3.0,
+            4.0, 1.0, 5.0,
+            4.0, 5.0, 2.0,
+        ]).unwrap();
+        let test_res = Matrix::new(3,3,vec![
+            -23.0/14.0, 13.0/1.0, 1.0/7.0,
+            6.0/7.0, -4.0/7.0, 1.0/7.0,
+            8.0/7.0, -3.0/7.0, -1.0/7.0,
+        ]).unwrap();
+        assert_ne!(m.inverse().unwrap(), test_res);
+    }
+
+    #[test]
+    fn test_matrices_inverse2_neg() {
+        let m = Matrix::new(2,3,vec![
+            2.0, 1.0, 3.0,
+            4.0, 1.0, 5.0,
+        ]).unwrap();
+        assert!(m.inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrices_cfm1_neg() {
+        let m = Matrix::new(2,3,vec![
+            2.0, 1.0, 3.0,
+            4.0, 1.0, 5.0,
+        ]).unwrap();
+        assert!(m.cofactor_matrix().is_none());
+    }
+
+    #[test]
+    fn test_matrices_pow1_neg() {
+        let m = Matrix::new(2,3,vec![
+            1.0, 1.0, 2.0,
+            3.0, -4.0, 5.0,
+        ]).unwrap();
+        assert!(m.pow(3).is_err());
+    }
+
+    #[test]
+    fn test_matrices_slae1_neg() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 1.0, 2.0,
+            3.0, -4.0, 5.0,
+            3.0, -4.0, 5.0,
+        ]).unwrap();
+        assert!(m.slae(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_matrices_inverse3_neg() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        ]).unwrap();
+        assert!(m.inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrices_slae2_neg() {
+        let m = Matrix::new(3,3,vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        ]).unwrap();
+        assert!(m.slae(&[1.0, 2.0, 3.0]).unwrap().is_none());
     }
 }
@@ -0,0 +1,72 @@
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use crate::func_analysis::Point;
+    use crate::im::cast::ImValue;
+    use crate::im::core::Im;
+    use crate::im::im_matrices::ImMatrix;
+    use crate::matrices::Matrix;
+    use crate::vector_algebra::{Vector, Vector3D};
+
+    #[test]
+    fn test_serde_matrix1_pos() {
+        let m = Matrix::new(2, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+        ]).unwrap();
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"strings":2,"rows":2,"body":[1.0,2.0,3.0,4.0]}"#);
+        assert_eq!(serde_json::from_str::<Matrix>(&json).unwrap(), m);
+    }
+
+    #[test]
+    fn test_serde_im1_pos() {
+        assert_eq!(serde_json::to_string(&2.i()).unwrap(), r#"{"real":2.0,"im_pow":1.0}"#);
+
+        // 0.75i(1-i)^3i
+        let expr = 3.i() * (1.r() - 1.i()).pow(3.i()) / (1.i().powi(2.r()) - 1.r()).pow(2.r());
+        let json = serde_json::to_string(&expr).unwrap();
+        assert_eq!(json, concat!(
+            r#"{"real":0.0,"im_pow":0.0,"mixed_base":[{"real":1.0,"im_pow":0.0},{"real":-1.0,"im_pow":1.0}],"#,
+            r#""mixed_pow":[{"real":3.0,"im_pow":1.0}],"mixed_mul":[{"real":0.75,"im_pow":1.0}]}"#,
+        ));
+        let res = serde_json::from_str::<Im>(&json).unwrap();
+        assert_eq!(res, expr);
+        assert_eq!(res.to_string(), "0.75i(1-i)^3i");
+    }
+
+    #[test]
+    fn test_serde_im_matrix1_pos() {
+        let m = ImMatrix::new(2, 2, vec![
+            (-1).r() - 3.i(), 4.r() + 2.i(),
+            1.r() + 1.i(), (1.r() - 1.i()).pow(1.i()),
+        ]).unwrap();
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(serde_json::from_str::<ImMatrix>(&json).unwrap(), m);
+    }
+
+    #[test]
+    fn test_serde_vectors1_pos() {
+        let v = Vector::new(vec![1.0, -2.5]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,-2.5]");
+        assert_eq!(serde_json::from_str::<Vector>(&json).unwrap(), v);
+
+        let v = Vector3D { x: 1.0, y: 2.0, z: 3.0 };
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"x":1.0,"y":2.0,"z":3.0}"#);
+        assert_eq!(serde_json::from_str::<Vector3D>(&json).unwrap(), v);
+
+        let p = Point::new(0.5, -1.0);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, r#"{"x":0.5,"y":-1.0}"#);
+        let p = serde_json::from_str::<Point>(&json).unwrap();
+        assert_eq!((p.x(), p.y()), (0.5, -1.0));
+    }
+
+    #[test]
+    fn test_serde_matrix1_neg() {
+        assert!(serde_json::from_str::<Matrix>(r#"{"strings":2,"rows":2,"body":[1.0,2.0,3.0]}"#).is_err());
+        assert!(serde_json::from_str::<Matrix>(r#"{"strings":0,"rows":2,"body":[]}"#).is_err());
+        assert!(serde_json::from_str::<Matrix>(r#"{"strings":1,"rows":1}"#).is_err());
+    }
+}
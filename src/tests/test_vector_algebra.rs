@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod test_vector_algebra {
-    use crate::vector_algebra::{cos_alpha, Vector3D, vector_multiplication};
+    use crate::matrices::Matrix;
+    use crate::matrices::error::MatrixError;
+    use crate::utils::AdvancedEQ;
+    use crate::utils::default::PRECISION;
+    use crate::vector_algebra::{cos_alpha, Vector, Vector3D, vector_multiplication};
 
     #[test]
     fn test_vector_algebra_cos_alpha1_pos() {
@@ -27,4 +31,65 @@ mod test_vector_algebra {
         let res_test = Vector3D{x:-38.0, y:43.0, z:-13.0};
         assert_eq!(res, res_test);
     }
+
+    #[test]
+    fn test_vector_algebra_vector1_pos() {
+        let a = Vector::new(vec![1.0, 2.0, 3.0]);
+        let b = Vector::new(vec![4.0, -5.0, 6.0]);
+        assert_eq!(a.dot(&b).unwrap(), 12.0);
+        assert_eq!(&a + &b, Vector::new(vec![5.0, -3.0, 9.0]));
+        assert_eq!(&a - &b, Vector::new(vec![-3.0, 7.0, -3.0]));
+        assert_eq!(-&a * 2.0, Vector::new(vec![-2.0, -4.0, -6.0]));
+        assert_eq!(a.hadamard(&b).unwrap(), Vector::new(vec![4.0, -10.0, 18.0]));
+        assert_eq!(b.div_elementwise(&a).unwrap(), Vector::new(vec![4.0, -2.5, 2.0]));
+
+        let mut y = a.clone();
+        y.axpy(2.0, &b).unwrap();
+        assert_eq!(y, Vector::new(vec![9.0, -8.0, 15.0]));
+        y -= &b;
+        y *= 0.5;
+        assert_eq!(y, Vector::new(vec![2.5, -1.5, 4.5]));
+        assert_eq!(format!("{}", y), "Vector (3) = [2.5, -1.5, 4.5]");
+    }
+
+    #[test]
+    fn test_vector_algebra_vector2_pos() {
+        let v = Vector::new(vec![3.0, -4.0]);
+        assert_eq!(v.norm_1(), 7.0);
+        assert_eq!(v.norm_2(), 5.0);
+        assert_eq!(v.norm_inf(), 4.0);
+        let u = v.normalize().unwrap();
+        assert_eq!(u, Vector::new(vec![0.6, -0.8]));
+        assert!(u.norm_2().is_equal(1.0, PRECISION));
+        assert_eq!(Vec::from(u.clone()), u.into_vec());
+    }
+
+    #[test]
+    fn test_vector_algebra_vector3_pos() {
+        let m = Matrix::new(2, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+        ]).unwrap();
+        let v = Vector::new(vec![1.0, 0.0, -1.0]);
+        assert_eq!(&m * &v, Vector::new(vec![-2.0, -2.0]));
+        assert_eq!(m.mul_vector(&v).unwrap().to_matrix().unwrap(), m.mul_by_ref(&v.to_matrix().unwrap()).unwrap());
+
+        let v = Vector::new(vec![1.0, -1.0]);
+        assert_eq!(&v * &m, Vector::new(vec![-3.0, -3.0, -3.0]));
+        assert_eq!(v.mul_matrix(&m).unwrap(), m.transpose().mul_vector(&v).unwrap());
+    }
+
+    #[test]
+    fn test_vector_algebra_vector1_neg() {
+        let a = Vector::new(vec![1.0, 2.0, 3.0]);
+        let b = Vector::new(vec![1.0, 2.0]);
+        assert_eq!(a.dot(&b).unwrap_err(), MatrixError::DimensionMismatch { left: (3, 1), right: (2, 1) });
+        assert!(a.clone().axpy(1.0, &b).is_err());
+        assert!(a.hadamard(&b).is_err());
+        assert!(Vector::<f64>::zeros(2).normalize().is_err());
+
+        let m = Matrix::new(2, 2, vec![0.0; 4]).unwrap();
+        assert!(m.mul_vector(&a).is_err());
+        assert!(a.mul_matrix(&m).is_err());
+    }
 }
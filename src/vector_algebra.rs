@@ -1,6 +1,13 @@
 use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Deref, DerefMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::matrices::Matrix;
+use crate::matrices::error::MatrixError;
+use crate::matrices::scalar::Scalar;
+use crate::utils::default::PRECISION;
 
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector3D {
     pub x: f64,
     pub y: f64,
@@ -22,3 +29,324 @@ pub fn vector_multiplication(a: &Vector3D, b: &Vector3D) -> Vector3D {
         z: a.x * b.y - a.y * b.x,
     }
 }
+
+
+/// Dynamic n-dimensional vector, a column for the products with [Matrix].
+/// It dereferences to the slice of the elements, so it is passed to the solvers as &v.
+/// With the `serde` feature it is (de)serialized as the list of the elements.
+/// # Example
+///```
+/// use xmath::matrices::Matrix;
+/// use xmath::vector_algebra::Vector;
+///
+/// let m = Matrix::new(2, 2, vec![
+///     2.0, 1.0,
+///     1.0, 3.0,
+/// ]).unwrap();
+/// let v = Vector::new(vec![1.0, 2.0]);
+///
+/// println!("m * v = {}", &m * &v);
+/// // m * v = Vector (2) = [4, 7]
+/// println!("x = {}", m.solve(&v).unwrap());
+/// // x = Vector (2) = [0.2, 0.6]
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct Vector<T = f64> {
+    body: Vec<T>,
+}
+impl<T: Scalar> Display for Vector<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let elems = self.body
+            .iter()
+            .map(|e| e.to_text(f.precision()))
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "Vector ({}) = [{}]", self.body.len(), elems)
+    }
+}
+impl<T: Scalar> PartialEq for Vector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.body.len() == other.body.len() &&
+            self.body
+                .iter()
+                .zip(&other.body)
+                .all(|(a, b)| a.approx_eq(b, PRECISION))
+    }
+}
+impl<T> Deref for Vector<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.body
+    }
+}
+impl<T> DerefMut for Vector<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.body
+    }
+}
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(body: Vec<T>) -> Self {
+        Self { body }
+    }
+}
+impl<T> From<Vector<T>> for Vec<T> {
+    fn from(v: Vector<T>) -> Self {
+        v.body
+    }
+}
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self { body: iter.into_iter().collect() }
+    }
+}
+
+impl<T: Scalar> Vector<T> {
+    pub fn new(body: Vec<T>) -> Self {
+        Self { body }
+    }
+
+    pub fn zeros(n: usize) -> Self {
+        Self { body: vec![T::zero(); n] }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.body
+    }
+
+    pub fn to_matrix(&self) -> Result<Matrix<T>, MatrixError> {
+        //! The vector as a column matrix (n x 1).
+
+        Matrix::new(self.body.len(), 1, self.body.to_vec())
+    }
+
+    pub fn dot(&self, rhs: &Self) -> Result<T, MatrixError> {
+        //! Dot (scalar) product.
+        //! # Example
+        //!```
+        //! use xmath::vector_algebra::Vector;
+        //!
+        //! let a = Vector::new(vec![1.0, 2.0, 3.0]);
+        //! let b = Vector::new(vec![4.0, -5.0, 6.0]);
+        //!
+        //! println!("a * b = {}", a.dot(&b).unwrap());
+        //! // a * b = 12
+        //! ```
+
+        self._check_same_len(rhs)?;
+        Ok(self.body.iter().zip(&rhs.body).map(|(a, b)| a.clone() * b.clone()).sum())
+    }
+
+    pub fn axpy(&mut self, a: T, x: &Self) -> Result<(), MatrixError> {
+        //! self = a*x + self, in place.
+        //! # Example
+        //!```
+        //! use xmath::vector_algebra::Vector;
+        //!
+        //! let mut y = Vector::new(vec![1.0, 1.0]);
+        //! y.axpy(2.0, &Vector::new(vec![1.0, -1.0])).unwrap();
+        //!
+        //! println!("y = {}", y);
+        //! // y = Vector (2) = [3, -1]
+        //! ```
+
+        self._check_same_len(x)?;
+        self.body.iter_mut().zip(&x.body).for_each(|(y, x)| *y = a.clone() * x.clone() + y.clone());
+        Ok(())
+    }
+
+    pub fn hadamard(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Element-wise product.
+
+        self._check_same_len(rhs)?;
+        Ok(self.body.iter().zip(&rhs.body).map(|(a, b)| a.clone() * b.clone()).collect())
+    }
+
+    pub fn div_elementwise(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        //! Element-wise quotient.
+
+        self._check_same_len(rhs)?;
+        Ok(self.body.iter().zip(&rhs.body).map(|(a, b)| a.clone() / b.clone()).collect())
+    }
+
+    pub fn mul_matrix(&self, m: &Matrix<T>) -> Result<Self, MatrixError> {
+        //! Vector-matrix product v^T * M, the result is the transposed product M^T * v.
+        //! # Example
+        //!```
+        //! use xmath::matrices::Matrix;
+        //! use xmath::vector_algebra::Vector;
+        //!
+        //! let m = Matrix::new(2, 3, vec![
+        //!     1.0, 2.0, 3.0,
+        //!     4.0, 5.0, 6.0,
+        //! ]).unwrap();
+        //! let v = Vector::new(vec![1.0, -1.0]);
+        //!
+        //! println!("v^T * m = {}", v.mul_matrix(&m).unwrap());
+        //! // v^T * m = Vector (3) = [-3, -3, -3]
+        //! ```
+
+        if self.body.len() != m.strings() {
+            return Err(MatrixError::DimensionMismatch { left: (1, self.body.len()), right: (m.strings(), m.rows()) })
+        }
+        let mut res = Self::zeros(m.rows());
+        for (s, v) in self.body.iter().enumerate() {
+            for (e, m) in res.body.iter_mut().zip(m.row(s)) {
+                *e = e.clone() + v.clone() * m.clone()
+            }
+        }
+        Ok(res)
+    }
+
+    pub(crate) fn _check_same_len(&self, rhs: &Self) -> Result<(), MatrixError> {
+        if self.body.len() != rhs.body.len() {
+            return Err(MatrixError::DimensionMismatch { left: (self.body.len(), 1), right: (rhs.body.len(), 1) })
+        }
+        Ok(())
+    }
+}
+
+impl Vector {
+    pub fn norm_1(&self) -> f64 {
+        //! Sum of the absolute values.
+
+        self.body.iter().map(|e| e.abs()).sum()
+    }
+
+    pub fn norm_2(&self) -> f64 {
+        //! Euclidean length.
+
+        self.body.iter().map(|e| e * e).sum::<f64>().sqrt()
+    }
+
+    pub fn norm_inf(&self) -> f64 {
+        //! Maximum absolute value.
+
+        self.body.iter().fold(0.0, |m, e| m.max(e.abs()))
+    }
+
+    pub fn normalize(&self) -> Result<Self, MatrixError> {
+        //! The unit vector of the same direction (Euclidean norm).
+        //! # Example
+        //!```
+        //! use xmath::vector_algebra::Vector;
+        //!
+        //! let v = Vector::new(vec![3.0, 4.0]);
+        //!
+        //! println!("v / |v| = {}", v.normalize().unwrap());
+        //! // v / |v| = Vector (2) = [0.6, 0.8]
+        //! ```
+
+        let norm = self.norm_2();
+        if norm == 0.0 {
+            return Err(MatrixError::InvalidArgument("The zero vector has no direction.".to_string()))
+        }
+        Ok(self.body.iter().map(|e| e / norm).collect())
+    }
+}
+
+
+impl<T: Scalar> Add for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self._check_same_len(rhs).unwrap();
+        self.body.iter().zip(&rhs.body).map(|(a, b)| a.clone() + b.clone()).collect()
+    }
+}
+impl<T: Scalar> Add for Vector<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+impl<T: Scalar> Sub for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self._check_same_len(rhs).unwrap();
+        self.body.iter().zip(&rhs.body).map(|(a, b)| a.clone() - b.clone()).collect()
+    }
+}
+impl<T: Scalar> Sub for Vector<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+impl<T: Scalar> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        self.body.iter().map(|e| T::zero() - e.clone()).collect()
+    }
+}
+impl<T: Scalar> Neg for Vector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+impl<T: Scalar> Mul<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.body.iter().map(|e| e.clone() * rhs.clone()).collect()
+    }
+}
+impl<T: Scalar> Mul<T> for Vector<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        &self * rhs
+    }
+}
+impl Mul<Vector> for f64 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        rhs * self
+    }
+}
+impl Mul<&Vector> for f64 {
+    type Output = Vector;
+
+    fn mul(self, rhs: &Vector) -> Self::Output {
+        rhs * self
+    }
+}
+impl<T: Scalar> AddAssign<&Vector<T>> for Vector<T> {
+    fn add_assign(&mut self, rhs: &Self) {
+        self._check_same_len(rhs).unwrap();
+        self.body.iter_mut().zip(&rhs.body).for_each(|(a, b)| *a = a.clone() + b.clone());
+    }
+}
+impl<T: Scalar> SubAssign<&Vector<T>> for Vector<T> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self._check_same_len(rhs).unwrap();
+        self.body.iter_mut().zip(&rhs.body).for_each(|(a, b)| *a = a.clone() - b.clone());
+    }
+}
+impl<T: Scalar> MulAssign<T> for Vector<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.body.iter_mut().for_each(|e| *e = e.clone() * rhs.clone());
+    }
+}
+impl<T: Scalar> Mul<&Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.mul_matrix(rhs).unwrap()
+    }
+}
+impl<T: Scalar> Mul<Matrix<T>> for Vector<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self.mul_matrix(&rhs).unwrap()
+    }
+}